@@ -0,0 +1,1392 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// A single cell in the Sudoku grid.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cell {
+    #[default]
+    Empty,
+    /// A clue pre-filled by the puzzle, not editable by the player.
+    Given(u8),
+    /// A digit entered by the player.
+    Filled(u8),
+}
+
+impl Cell {
+    /// The digit held by this cell, if any.
+    pub fn digit(&self) -> Option<u8> {
+        match self {
+            Cell::Empty => None,
+            Cell::Given(d) | Cell::Filled(d) => Some(*d),
+        }
+    }
+}
+
+/// A supported Sudoku board size, with the dimensions of its boxes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BoardSize {
+    /// A 4x4 mini board, with 2x2 boxes.
+    Mini4,
+    /// A 6x6 mini board, with 2x3 boxes.
+    Mini6,
+    /// The classic 9x9 board, with 3x3 boxes.
+    #[default]
+    Classic9,
+    /// A 16x16 hex board, with 4x4 boxes, using digits 1-9 and A-G for its
+    /// sixteen values.
+    Classic16,
+}
+
+impl BoardSize {
+    /// The length of a side of the grid, and the highest digit it holds.
+    pub fn side(self) -> usize {
+        match self {
+            BoardSize::Mini4 => 4,
+            BoardSize::Mini6 => 6,
+            BoardSize::Classic9 => 9,
+            BoardSize::Classic16 => 16,
+        }
+    }
+
+    /// The `(rows, cols)` dimensions of a single box.
+    pub fn box_dims(self) -> (usize, usize) {
+        match self {
+            BoardSize::Mini4 => (2, 2),
+            BoardSize::Mini6 => (2, 3),
+            BoardSize::Classic9 => (3, 3),
+            BoardSize::Classic16 => (4, 4),
+        }
+    }
+
+    /// The top-left cell of the 1-indexed box `box_number`, numbered
+    /// left-to-right then top-to-bottom — the same order a phone keypad's
+    /// digits read over a 3x3 grid. Returns `None` if this board doesn't
+    /// have that many boxes.
+    pub fn box_origin(self, box_number: usize) -> Option<(usize, usize)> {
+        let side = self.side();
+        let (box_rows, box_cols) = self.box_dims();
+        let boxes_per_row = side / box_cols;
+        let total_boxes = boxes_per_row * (side / box_rows);
+        if box_number == 0 || box_number > total_boxes {
+            return None;
+        }
+
+        let b = box_number - 1;
+        Some(((b / boxes_per_row) * box_rows, (b % boxes_per_row) * box_cols))
+    }
+}
+
+impl fmt::Display for BoardSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{0}x{0}", self.side())
+    }
+}
+
+/// Renders `digit` (1-16) as a single character: `1`-`9`, then `A`-`G` for
+/// the six values a `Classic16` board's boxes add beyond a classic 9x9.
+pub fn digit_to_char(digit: u8) -> char {
+    match digit {
+        1..=9 => (b'0' + digit) as char,
+        10..=16 => (b'A' + digit - 10) as char,
+        _ => '?',
+    }
+}
+
+/// Parses a single entry character into a digit (`0` for blank) valid on a
+/// board of `size`: `0`-`9` always, plus case-insensitive `A`-`G` for the
+/// extra values a `Classic16` board holds.
+pub fn char_to_digit(c: char, size: BoardSize) -> Option<u8> {
+    match c {
+        '0'..='9' => Some(c.to_digit(10).unwrap() as u8),
+        'A'..='G' | 'a'..='g' if size == BoardSize::Classic16 => Some(c.to_ascii_uppercase() as u8 - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// A candidate ruled out by a technique like `Board::pointing_pairs`: not a
+/// placement, just one digit removed from one cell's remaining candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elimination {
+    pub row: usize,
+    pub col: usize,
+    pub digit: u8,
+}
+
+/// One of nine highlight colors a player can tag a cell with, for manual
+/// techniques like coloring or chains. Independent of the cell's value or
+/// pencil marks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnnotationColor {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Cyan,
+    Magenta,
+    Gray,
+}
+
+impl AnnotationColor {
+    /// The color a `1`-`9` key picks while annotating. `None` for any other
+    /// digit, including `0`, which clears a cell's annotation instead.
+    pub fn from_digit(digit: u8) -> Option<Self> {
+        match digit {
+            1 => Some(AnnotationColor::Red),
+            2 => Some(AnnotationColor::Orange),
+            3 => Some(AnnotationColor::Yellow),
+            4 => Some(AnnotationColor::Green),
+            5 => Some(AnnotationColor::Blue),
+            6 => Some(AnnotationColor::Purple),
+            7 => Some(AnnotationColor::Cyan),
+            8 => Some(AnnotationColor::Magenta),
+            9 => Some(AnnotationColor::Gray),
+            _ => None,
+        }
+    }
+}
+
+/// A Sudoku board of some `BoardSize`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Board {
+    size: BoardSize,
+    cells: Vec<Vec<Cell>>,
+    notes: Vec<Vec<HashSet<u8>>>,
+    /// Cells flagged by `check my progress` as not matching the solution.
+    /// Cleared as soon as the cell is edited again.
+    wrong: Vec<Vec<bool>>,
+    /// Highlight colors the player has tagged cells with, for manual
+    /// techniques like coloring or chains. Defaults to empty, read as
+    /// `None` everywhere, for boards saved before this field existed.
+    #[serde(default)]
+    annotations: Vec<Vec<Option<AnnotationColor>>>,
+    /// Whether the X-Sudoku variant is active: the two main diagonals must
+    /// also hold every digit exactly once, on top of the usual row, column,
+    /// and box constraints. Defaults to `false` for boards saved before
+    /// this field existed.
+    #[serde(default)]
+    diagonal: bool,
+}
+
+impl Board {
+    /// Creates an empty board of the given size.
+    pub fn new(size: BoardSize) -> Self {
+        let side = size.side();
+        Self {
+            size,
+            cells: vec![vec![Cell::Empty; side]; side],
+            notes: vec![vec![HashSet::new(); side]; side],
+            wrong: vec![vec![false; side]; side],
+            annotations: vec![vec![None; side]; side],
+            diagonal: false,
+        }
+    }
+
+    /// This board's size.
+    pub fn size(&self) -> BoardSize {
+        self.size
+    }
+
+    /// Whether the X-Sudoku diagonal constraint is active on this board.
+    pub fn is_diagonal(&self) -> bool {
+        self.diagonal
+    }
+
+    /// Turns the X-Sudoku diagonal constraint on or off.
+    pub fn set_diagonal(&mut self, diagonal: bool) {
+        self.diagonal = diagonal;
+    }
+
+    /// Returns the cell at `(row, col)`.
+    pub fn get(&self, row: usize, col: usize) -> Cell {
+        self.cells[row][col]
+    }
+
+    /// Sets the cell at `(row, col)`, clearing any pencil marks and
+    /// `check my progress` mark it held.
+    pub fn set(&mut self, row: usize, col: usize, cell: Cell) {
+        self.cells[row][col] = cell;
+        self.notes[row][col].clear();
+        self.wrong[row][col] = false;
+    }
+
+    /// The pencil-mark candidates jotted in an empty cell.
+    pub fn notes(&self, row: usize, col: usize) -> &HashSet<u8> {
+        &self.notes[row][col]
+    }
+
+    /// Restores a cell's digit and pencil marks exactly, for undo/redo.
+    /// Unlike `set`, this does not clear the notes it's given. Like `set`,
+    /// it clears any `check my progress` mark, since the cell is changing.
+    pub fn restore(&mut self, row: usize, col: usize, cell: Cell, notes: HashSet<u8>) {
+        self.cells[row][col] = cell;
+        self.notes[row][col] = notes;
+        self.wrong[row][col] = false;
+    }
+
+    /// Flags the cell at `(row, col)` as not matching the solution, for
+    /// `check my progress`. Cleared automatically the next time the cell
+    /// is edited via `set` or `restore`.
+    pub fn mark_wrong(&mut self, row: usize, col: usize) {
+        self.wrong[row][col] = true;
+    }
+
+    /// Whether the cell at `(row, col)` is currently flagged as wrong.
+    pub fn is_wrong(&self, row: usize, col: usize) -> bool {
+        self.wrong[row][col]
+    }
+
+    /// The highlight color tagged onto the cell at `(row, col)`, if any.
+    /// Independent of the cell's value and pencil marks.
+    pub fn annotation(&self, row: usize, col: usize) -> Option<AnnotationColor> {
+        self.annotations.get(row).and_then(|r| r.get(col)).copied().flatten()
+    }
+
+    /// Tags (or, given `None`, clears) the cell at `(row, col)`'s highlight
+    /// color. Unlike `set`, this survives the cell's value being changed,
+    /// since an annotation marks the cell itself, not what's in it.
+    pub fn set_annotation(&mut self, row: usize, col: usize, color: Option<AnnotationColor>) {
+        let side = self.size.side();
+        if self.annotations.len() < side {
+            self.annotations = vec![vec![None; side]; side];
+        }
+        self.annotations[row][col] = color;
+    }
+
+    /// Toggles `digit` as a pencil-mark candidate in an empty cell. Has no
+    /// effect on a cell that already holds a real digit.
+    pub fn toggle_note(&mut self, row: usize, col: usize, digit: u8) {
+        if self.get(row, col) != Cell::Empty {
+            return;
+        }
+        let notes = &mut self.notes[row][col];
+        if !notes.remove(&digit) {
+            notes.insert(digit);
+        }
+    }
+
+    /// The digits `1..=side` that don't already appear in `row`'s row,
+    /// column, or box — i.e. the legal candidates for that cell, regardless
+    /// of what (if anything) currently occupies it.
+    pub fn candidates(&self, row: usize, col: usize) -> HashSet<u8> {
+        let side = self.size.side() as u8;
+        let (box_rows, box_cols) = self.size.box_dims();
+        let box_row = (row / box_rows) * box_rows;
+        let box_col = (col / box_cols) * box_cols;
+
+        let mut used = HashSet::new();
+        for i in 0..self.size.side() {
+            if let Some(digit) = self.get(row, i).digit() {
+                used.insert(digit);
+            }
+            if let Some(digit) = self.get(i, col).digit() {
+                used.insert(digit);
+            }
+        }
+        for r in box_row..box_row + box_rows {
+            for c in box_col..box_col + box_cols {
+                if let Some(digit) = self.get(r, c).digit() {
+                    used.insert(digit);
+                }
+            }
+        }
+        if self.diagonal {
+            for (r, c) in self.diagonal_cells(row, col) {
+                if let Some(digit) = self.get(r, c).digit() {
+                    used.insert(digit);
+                }
+            }
+        }
+
+        (1..=side).filter(|digit| !used.contains(digit)).collect()
+    }
+
+    /// The cells on whichever main diagonal(s) `(row, col)` sits on, not
+    /// including `(row, col)` itself. Empty if the diagonal variant is off
+    /// or the cell isn't on a diagonal.
+    fn diagonal_cells(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let side = self.size.side();
+        let mut cells = Vec::new();
+        if row == col {
+            cells.extend((0..side).map(|i| (i, i)));
+        }
+        if row + col == side - 1 {
+            cells.extend((0..side).map(|i| (i, side - 1 - i)));
+        }
+        cells.retain(|&cell| cell != (row, col));
+        cells
+    }
+
+    /// Every empty cell where a digit has only one legal placement left
+    /// within its row, column, or box, even though the cell itself may
+    /// still have other candidates. Each is returned as `(row, col, digit)`.
+    pub fn hidden_singles(&self) -> Vec<(usize, usize, u8)> {
+        let side = self.size.side();
+        let (box_rows, box_cols) = self.size.box_dims();
+        let boxes_per_row = side / box_cols;
+        let mut found = Vec::new();
+
+        let rows = (0..side).map(|row| (0..side).map(move |col| (row, col)).collect::<Vec<_>>());
+        let cols = (0..side).map(|col| (0..side).map(move |row| (row, col)).collect::<Vec<_>>());
+        let boxes = (0..side).map(move |b| {
+            let box_row = (b / boxes_per_row) * box_rows;
+            let box_col = (b % boxes_per_row) * box_cols;
+            (box_row..box_row + box_rows)
+                .flat_map(move |row| (box_col..box_col + box_cols).map(move |col| (row, col)))
+                .collect::<Vec<_>>()
+        });
+
+        for unit in rows.chain(cols).chain(boxes) {
+            for digit in 1..=side as u8 {
+                let mut holders = unit
+                    .iter()
+                    .copied()
+                    .filter(|&(r, c)| self.get(r, c) == Cell::Empty && self.candidates(r, c).contains(&digit));
+                let Some(first) = holders.next() else {
+                    continue;
+                };
+                if holders.next().is_none() {
+                    found.push((first.0, first.1, digit));
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Within each box, if a digit's remaining candidate cells all share a
+    /// row or column, that digit can't appear anywhere else in that
+    /// row/column outside the box, so it can be eliminated from those
+    /// cells' candidates too. The same technique as `rating`'s own pointing
+    /// pairs pass, but working against this live board rather than a
+    /// synthetic candidate-grid simulation.
+    pub fn pointing_pairs(&self) -> Vec<Elimination> {
+        let side = self.size.side();
+        let (box_rows, box_cols) = self.size.box_dims();
+        let boxes_per_row = side / box_cols;
+        let mut eliminations = Vec::new();
+
+        for b in 0..side {
+            let box_row = (b / boxes_per_row) * box_rows;
+            let box_col = (b % boxes_per_row) * box_cols;
+            let cells: Vec<(usize, usize)> = (box_row..box_row + box_rows)
+                .flat_map(|r| (box_col..box_col + box_cols).map(move |c| (r, c)))
+                .collect();
+
+            for digit in 1..=side as u8 {
+                let holders: Vec<(usize, usize)> = cells
+                    .iter()
+                    .copied()
+                    .filter(|&(r, c)| self.get(r, c) == Cell::Empty && self.candidates(r, c).contains(&digit))
+                    .collect();
+                if holders.len() < 2 {
+                    continue;
+                }
+
+                if holders.iter().all(|&(r, _)| r == holders[0].0) {
+                    let row = holders[0].0;
+                    for col in 0..side {
+                        if (box_col..box_col + box_cols).contains(&col) {
+                            continue;
+                        }
+                        if self.get(row, col) == Cell::Empty && self.candidates(row, col).contains(&digit) {
+                            eliminations.push(Elimination { row, col, digit });
+                        }
+                    }
+                } else if holders.iter().all(|&(_, c)| c == holders[0].1) {
+                    let col = holders[0].1;
+                    for row in 0..side {
+                        if (box_row..box_row + box_rows).contains(&row) {
+                            continue;
+                        }
+                        if self.get(row, col) == Cell::Empty && self.candidates(row, col).contains(&digit) {
+                            eliminations.push(Elimination { row, col, digit });
+                        }
+                    }
+                }
+            }
+        }
+
+        eliminations
+    }
+
+    /// Within a unit, if two digits' remaining candidate cells are exactly
+    /// the same two cells, those two cells must between them hold those two
+    /// digits, so every other candidate can be eliminated from them — even
+    /// though, unlike a naked pair, neither cell may have been reduced to
+    /// just those two digits yet. Complements `pointing_pairs`, which
+    /// eliminates by a digit's position rather than by pairing candidates.
+    pub fn hidden_pairs(&self) -> Vec<Elimination> {
+        let side = self.size.side() as u8;
+        let mut eliminations = Vec::new();
+
+        for unit in self.units() {
+            for d1 in 1..=side {
+                let d1_holders: Vec<(usize, usize)> = unit
+                    .iter()
+                    .copied()
+                    .filter(|&(r, c)| self.get(r, c) == Cell::Empty && self.candidates(r, c).contains(&d1))
+                    .collect();
+                if d1_holders.len() != 2 {
+                    continue;
+                }
+
+                for d2 in (d1 + 1)..=side {
+                    let d2_holders: Vec<(usize, usize)> = unit
+                        .iter()
+                        .copied()
+                        .filter(|&(r, c)| self.get(r, c) == Cell::Empty && self.candidates(r, c).contains(&d2))
+                        .collect();
+                    if d2_holders != d1_holders {
+                        continue;
+                    }
+
+                    for &(row, col) in &d1_holders {
+                        for digit in self.candidates(row, col) {
+                            if digit != d1 && digit != d2 {
+                                eliminations.push(Elimination { row, col, digit });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        eliminations
+    }
+
+    /// The X-Wing technique: if a digit's remaining candidates in two rows
+    /// are confined to the same two columns, one of those rows must place
+    /// the digit in each column, so it can be eliminated from every other
+    /// cell in those columns. Also checked the other way around (two
+    /// columns sharing the same two rows), since the same rectangle
+    /// argument rules the digit out along the other dimension too.
+    pub fn x_wing(&self) -> Vec<Elimination> {
+        let side = self.size.side();
+        let mut eliminations = Vec::new();
+
+        for digit in 1..=side as u8 {
+            let mut rows_by_columns: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+            for row in 0..side {
+                let cols: Vec<usize> = (0..side)
+                    .filter(|&col| self.get(row, col) == Cell::Empty && self.candidates(row, col).contains(&digit))
+                    .collect();
+                if cols.len() == 2 {
+                    rows_by_columns.entry(cols).or_default().push(row);
+                }
+            }
+            for (cols, rows) in &rows_by_columns {
+                if rows.len() != 2 {
+                    continue;
+                }
+                for &col in cols {
+                    for row in 0..side {
+                        if rows.contains(&row) {
+                            continue;
+                        }
+                        if self.get(row, col) == Cell::Empty && self.candidates(row, col).contains(&digit) {
+                            eliminations.push(Elimination { row, col, digit });
+                        }
+                    }
+                }
+            }
+
+            let mut cols_by_rows: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+            for col in 0..side {
+                let rows: Vec<usize> = (0..side)
+                    .filter(|&row| self.get(row, col) == Cell::Empty && self.candidates(row, col).contains(&digit))
+                    .collect();
+                if rows.len() == 2 {
+                    cols_by_rows.entry(rows).or_default().push(col);
+                }
+            }
+            for (rows, cols) in &cols_by_rows {
+                if cols.len() != 2 {
+                    continue;
+                }
+                for &row in rows {
+                    for col in 0..side {
+                        if cols.contains(&col) {
+                            continue;
+                        }
+                        if self.get(row, col) == Cell::Empty && self.candidates(row, col).contains(&digit) {
+                            eliminations.push(Elimination { row, col, digit });
+                        }
+                    }
+                }
+            }
+        }
+
+        eliminations
+    }
+
+    /// Every row, column, and box's cells, plus (when the X-Sudoku diagonal
+    /// variant is on) the two main diagonals — i.e. every group of cells
+    /// that must not contain a repeated digit.
+    fn units(&self) -> Vec<Vec<(usize, usize)>> {
+        let side = self.size.side();
+        let (box_rows, box_cols) = self.size.box_dims();
+        let boxes_per_row = side / box_cols;
+
+        let mut units: Vec<Vec<(usize, usize)>> = Vec::new();
+        units.extend((0..side).map(|row| (0..side).map(|col| (row, col)).collect()));
+        units.extend((0..side).map(|col| (0..side).map(|row| (row, col)).collect()));
+        units.extend((0..side).map(|b| {
+            let box_row = (b / boxes_per_row) * box_rows;
+            let box_col = (b % boxes_per_row) * box_cols;
+            (box_row..box_row + box_rows)
+                .flat_map(|row| (box_col..box_col + box_cols).map(move |col| (row, col)))
+                .collect()
+        }));
+        if self.diagonal {
+            units.push((0..side).map(|i| (i, i)).collect());
+            units.push((0..side).map(|i| (i, side - 1 - i)).collect());
+        }
+
+        units
+    }
+
+    /// All cells that share a digit with another cell in the same row,
+    /// column, or box (and, in the X-Sudoku variant, diagonal). Givens are
+    /// flagged too, if they happen to conflict.
+    pub fn conflicts(&self) -> HashSet<(usize, usize)> {
+        let mut conflicts = HashSet::new();
+
+        for unit in self.units() {
+            let mut seen: std::collections::HashMap<u8, Vec<(usize, usize)>> =
+                std::collections::HashMap::new();
+            for (row, col) in unit {
+                if let Some(digit) = self.get(row, col).digit() {
+                    seen.entry(digit).or_default().push((row, col));
+                }
+            }
+            for cells in seen.into_values() {
+                if cells.len() > 1 {
+                    conflicts.extend(cells);
+                }
+            }
+        }
+
+        conflicts
+    }
+
+    /// Checks that this board's givens don't already conflict with each
+    /// other (e.g. two identical digits in the same row), before they're
+    /// locked in as the puzzle's clues. Unlike `conflicts`, which flags
+    /// every cell sharing a digit including player entries, this only ever
+    /// looks at `Cell::Given`s, since it's meant to validate a puzzle before
+    /// play starts.
+    pub fn givens_are_valid(&self) -> Result<(), Vec<(usize, usize)>> {
+        let mut conflicting: HashSet<(usize, usize)> = HashSet::new();
+
+        for unit in self.units() {
+            let mut seen: std::collections::HashMap<u8, Vec<(usize, usize)>> =
+                std::collections::HashMap::new();
+            for (row, col) in unit {
+                if let Cell::Given(digit) = self.get(row, col) {
+                    seen.entry(digit).or_default().push((row, col));
+                }
+            }
+            for cells in seen.into_values() {
+                if cells.len() > 1 {
+                    conflicting.extend(cells);
+                }
+            }
+        }
+
+        if conflicting.is_empty() {
+            return Ok(());
+        }
+        let mut positions: Vec<(usize, usize)> = conflicting.into_iter().collect();
+        positions.sort();
+        Err(positions)
+    }
+
+    /// Clears every player-filled digit and pencil mark, leaving givens
+    /// untouched, so a player can restart the current puzzle without
+    /// generating a new one.
+    pub fn reset_to_givens(&mut self) {
+        let side = self.size.side();
+        for row in 0..side {
+            for col in 0..side {
+                if matches!(self.cells[row][col], Cell::Filled(_)) {
+                    self.cells[row][col] = Cell::Empty;
+                }
+                self.notes[row][col].clear();
+                self.wrong[row][col] = false;
+            }
+        }
+    }
+
+    /// Whether the player has filled in any digit, as opposed to a fresh
+    /// puzzle holding only its givens.
+    pub fn has_entries(&self) -> bool {
+        self.cells.iter().flatten().any(|cell| matches!(cell, Cell::Filled(_)))
+    }
+
+    /// How many times each digit `1..=side` currently appears on the
+    /// board (as a given or a filled entry), indexed `[digit - 1]`. Useful
+    /// for a UI showing how many of each digit are still unplaced.
+    pub fn digit_counts(&self) -> Vec<u8> {
+        let mut counts = vec![0u8; self.size.side()];
+        for cell in self.cells.iter().flatten() {
+            if let Some(digit) = cell.digit() {
+                counts[digit as usize - 1] += 1;
+            }
+        }
+        counts
+    }
+
+    /// How many cells currently hold a digit (given or filled), out of the
+    /// board's total cell count. Useful for a completion readout.
+    pub fn filled_count(&self) -> usize {
+        self.cells.iter().flatten().filter(|cell| cell.digit().is_some()).count()
+    }
+
+    /// How many cells are puzzle givens, out of the board's total cell
+    /// count. Unlike `filled_count`, this ignores the player's own entries,
+    /// so it stays fixed for the life of a puzzle — useful for showing how
+    /// sparse a generated puzzle turned out to be.
+    pub fn given_count(&self) -> usize {
+        self.cells.iter().flatten().filter(|cell| matches!(cell, Cell::Given(_))).count()
+    }
+
+    /// Whether every cell holds a digit and no row, column, or box has a
+    /// duplicate.
+    pub fn is_solved(&self) -> bool {
+        self.cells
+            .iter()
+            .flatten()
+            .all(|cell| cell.digit().is_some())
+            && self.conflicts().is_empty()
+    }
+
+    /// Serializes the board to a standard puzzle line of `size*size`
+    /// characters, using `.` for blank cells. Player-filled digits are
+    /// written the same as givens; the distinction doesn't survive the
+    /// round trip.
+    #[allow(dead_code)]
+    pub fn to_str_line(&self) -> String {
+        let side = self.size.side();
+        (0..side)
+            .flat_map(|row| (0..side).map(move |col| (row, col)))
+            .map(|(row, col)| match self.get(row, col).digit() {
+                Some(digit) => char::from_digit(digit as u32, 10).unwrap_or('?'),
+                None => '.',
+            })
+            .collect()
+    }
+
+    /// Renders the board as a boxed ASCII grid, suitable for pasting into
+    /// chat or a text file. Blanks are shown as `.`; givens and
+    /// player-filled digits both show their digit, matching `to_str_line`'s
+    /// convention that the distinction doesn't survive serialization.
+    pub fn to_ascii_grid(&self) -> String {
+        let side = self.size.side();
+        let (box_rows, box_cols) = self.size.box_dims();
+
+        let box_line = format!("+{}+", vec!["-".repeat(box_cols * 2 + 1); side / box_cols].join("+"));
+
+        let mut out = String::new();
+        for row in 0..side {
+            if row % box_rows == 0 {
+                out.push_str(&box_line);
+                out.push('\n');
+            }
+            out.push('|');
+            for col in 0..side {
+                let symbol = match self.get(row, col).digit() {
+                    Some(digit) => char::from_digit(digit as u32, 10).unwrap_or('?'),
+                    None => '.',
+                };
+                out.push(' ');
+                out.push(symbol);
+                if (col + 1) % box_cols == 0 {
+                    out.push_str(" |");
+                }
+            }
+            out.push('\n');
+        }
+        out.push_str(&box_line);
+        out.push('\n');
+        out
+    }
+
+    /// Builds a new board by moving every cell (and its notes, wrongness,
+    /// and annotation) from `(row, col)` to wherever `map` sends it,
+    /// keeping the board's size and diagonal flag. Shared by every
+    /// position-preserving transformation below.
+    fn transformed(&self, map: impl Fn(usize, usize, usize) -> (usize, usize)) -> Board {
+        let side = self.size.side();
+        let mut result = Board::new(self.size);
+        result.set_diagonal(self.diagonal);
+        for row in 0..side {
+            for col in 0..side {
+                let (new_row, new_col) = map(row, col, side);
+                result.cells[new_row][new_col] = self.cells[row][col];
+                result.notes[new_row][new_col] = self.notes[row][col].clone();
+                result.wrong[new_row][new_col] = self.wrong[row][col];
+                result.annotations[new_row][new_col] = self.annotations[row][col];
+            }
+        }
+        result
+    }
+
+    /// Rotates the board 90° clockwise: the cell at `(row, col)` moves to
+    /// `(col, side - 1 - row)`. Row and column constraints turn with the
+    /// grid regardless of shape; the box constraint only turns with it when
+    /// boxes are square (`box_dims().0 == box_dims().1`), since a 90° turn
+    /// swaps a box's row and column extents. Applying this four times is a
+    /// full turn and returns the original.
+    pub fn rotated(&self) -> Board {
+        self.transformed(|row, col, side| (col, side - 1 - row))
+    }
+
+    /// Reflects the board left-to-right: the cell at `(row, col)` moves to
+    /// `(row, side - 1 - col)`. Rows and boxes keep their contents, just
+    /// mirrored, so a solved board reflects into another solved board.
+    pub fn reflected(&self) -> Board {
+        self.transformed(|row, col, _| (row, self.size.side() - 1 - col))
+    }
+
+    /// Swaps two bands (each `box_dims().0` rows tall) wholesale, leaving
+    /// every row's own contents untouched. Sudoku's row, column, and box
+    /// constraints don't care which band a row-group sits in, only that
+    /// the rows within a box stay together, so this preserves solvability.
+    pub fn with_bands_swapped(&self, a: usize, b: usize) -> Board {
+        let (box_rows, _) = self.size.box_dims();
+        self.transformed(|row, col, _| {
+            let band = row / box_rows;
+            let band = if band == a { b } else if band == b { a } else { band };
+            (band * box_rows + row % box_rows, col)
+        })
+    }
+
+    /// Swaps two stacks (each `box_dims().1` columns wide) wholesale, the
+    /// column analogue of `with_bands_swapped`.
+    pub fn with_stacks_swapped(&self, a: usize, b: usize) -> Board {
+        let (_, box_cols) = self.size.box_dims();
+        self.transformed(|row, col, _| {
+            let stack = col / box_cols;
+            let stack = if stack == a { b } else if stack == b { a } else { stack };
+            (row, stack * box_cols + col % box_cols)
+        })
+    }
+
+    /// Relabels every digit on the board, where `mapping[d - 1]` is the
+    /// digit that should replace `d`. A permutation of `1..=side` preserves
+    /// solvability, since it's just renaming values consistently
+    /// everywhere: every row, column, and box still holds each label
+    /// exactly once.
+    pub fn relabeled(&self, mapping: &[u8]) -> Board {
+        let side = self.size.side();
+        let relabel = |digit: u8| mapping[digit as usize - 1];
+
+        let mut result = Board::new(self.size);
+        result.set_diagonal(self.diagonal);
+        for row in 0..side {
+            for col in 0..side {
+                result.cells[row][col] = match self.cells[row][col] {
+                    Cell::Empty => Cell::Empty,
+                    Cell::Given(digit) => Cell::Given(relabel(digit)),
+                    Cell::Filled(digit) => Cell::Filled(relabel(digit)),
+                };
+                result.notes[row][col] = self.notes[row][col].iter().map(|&digit| relabel(digit)).collect();
+                result.wrong[row][col] = self.wrong[row][col];
+                result.annotations[row][col] = self.annotations[row][col];
+            }
+        }
+        result
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new(BoardSize::default())
+    }
+}
+
+/// An error parsing a standard puzzle line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string's length didn't match a supported board size's cell
+    /// count (16, 36, 81, or 256).
+    WrongLength(usize),
+    /// A character wasn't a digit or one of the accepted blank markers.
+    InvalidChar(char),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::WrongLength(len) => {
+                write!(f, "expected 16, 36, 81, or 256 characters, got {len}")
+            }
+            ParseError::InvalidChar(c) => {
+                write!(f, "invalid character '{c}', expected a digit or '.'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    /// Parses a puzzle line into a board, treating digits as givens and
+    /// `0`/`.` as blanks. The line's length picks the board size: 16
+    /// characters for 4x4, 36 for 6x6, 81 for 9x9, or 256 for the 16x16 hex
+    /// variant, whose givens above 9 are the letters `A`-`G`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        let size = match chars.len() {
+            16 => BoardSize::Mini4,
+            36 => BoardSize::Mini6,
+            81 => BoardSize::Classic9,
+            256 => BoardSize::Classic16,
+            other => return Err(ParseError::WrongLength(other)),
+        };
+        let side = size.side();
+
+        let mut board = Board::new(size);
+        for (i, c) in chars.into_iter().enumerate() {
+            let (row, col) = (i / side, i % side);
+            match c {
+                '.' | '0' => {}
+                other => match char_to_digit(other, size) {
+                    Some(digit) => board.set(row, col, Cell::Given(digit)),
+                    None => return Err(ParseError::InvalidChar(other)),
+                },
+            }
+        }
+
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_board_is_all_empty() {
+        let board = Board::default();
+        for row in 0..9 {
+            for col in 0..9 {
+                assert_eq!(board.get(row, col), Cell::Empty);
+            }
+        }
+    }
+
+    #[test]
+    fn set_and_get_round_trip() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(8, 8, Cell::Filled(3));
+        assert_eq!(board.get(0, 0), Cell::Given(5));
+        assert_eq!(board.get(8, 8), Cell::Filled(3));
+        assert_eq!(board.get(4, 4), Cell::Empty);
+    }
+
+    #[test]
+    fn box_origin_numbers_boxes_like_a_phone_keypad() {
+        assert_eq!(BoardSize::Classic9.box_origin(1), Some((0, 0)));
+        assert_eq!(BoardSize::Classic9.box_origin(5), Some((3, 3)));
+        assert_eq!(BoardSize::Classic9.box_origin(9), Some((6, 6)));
+    }
+
+    #[test]
+    fn box_origin_is_none_out_of_range() {
+        assert_eq!(BoardSize::Classic9.box_origin(0), None);
+        assert_eq!(BoardSize::Classic9.box_origin(10), None);
+        assert_eq!(BoardSize::Mini4.box_origin(4), Some((2, 2)));
+        assert_eq!(BoardSize::Mini4.box_origin(5), None);
+    }
+
+    #[test]
+    fn digit_counts_tallies_givens_and_filled_entries() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(1, 1, Cell::Given(5));
+        board.set(2, 2, Cell::Filled(3));
+
+        let counts = board.digit_counts();
+        assert_eq!(counts[4], 2);
+        assert_eq!(counts[2], 1);
+        assert_eq!(counts[0], 0);
+        assert_eq!(counts.len(), 9);
+    }
+
+    #[test]
+    fn filled_count_tallies_givens_and_filled_entries_but_not_empty_cells() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(1, 1, Cell::Filled(3));
+
+        assert_eq!(board.filled_count(), 2);
+    }
+
+    #[test]
+    fn given_count_counts_only_givens_not_player_entries() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(1, 1, Cell::Given(3));
+        board.set(2, 2, Cell::Filled(7));
+
+        assert_eq!(board.given_count(), 2);
+    }
+
+    #[test]
+    fn candidates_excludes_digits_seen_in_the_row_column_and_box() {
+        let mut board = Board::default();
+        board.set(0, 1, Cell::Given(1));
+        board.set(1, 0, Cell::Given(2));
+        board.set(2, 2, Cell::Given(3));
+        board.set(8, 0, Cell::Given(4));
+
+        let candidates = board.candidates(0, 0);
+        assert!(!candidates.contains(&1));
+        assert!(!candidates.contains(&2));
+        assert!(!candidates.contains(&3));
+        assert!(!candidates.contains(&4));
+        assert_eq!(candidates.len(), 5);
+    }
+
+    #[test]
+    fn candidates_can_narrow_to_exactly_one_digit() {
+        let mut board = Board::default();
+        for digit in 2..=9u8 {
+            board.set(0, digit as usize - 1, Cell::Given(digit));
+        }
+
+        let candidates = board.candidates(0, 0);
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates.contains(&1));
+    }
+
+    #[test]
+    fn hidden_singles_finds_a_digit_confined_to_one_cell_in_a_row() {
+        let mut board = Board::default();
+        for (col, digit) in (1..=7u8).enumerate() {
+            board.set(0, col, Cell::Given(digit));
+        }
+        // Removes 9 from column 8's candidates, so within row 0 only column
+        // 7 can still hold it, even though column 7 also has candidate 8.
+        board.set(5, 8, Cell::Given(9));
+
+        let hidden_singles = board.hidden_singles();
+        assert!(hidden_singles.contains(&(0, 7, 9)));
+    }
+
+    #[test]
+    fn hidden_singles_finds_nothing_on_an_empty_board() {
+        let board = Board::default();
+        assert!(board.hidden_singles().is_empty());
+    }
+
+    #[test]
+    fn pointing_pairs_eliminates_the_digit_from_the_rest_of_the_row_outside_the_box() {
+        let mut board = Board::default();
+        // Box (0, 0)'s rows 1 and 2 are entirely filled with givens other
+        // than 5, so within that box only row 0 can still hold a 5.
+        board.set(1, 0, Cell::Given(1));
+        board.set(1, 1, Cell::Given(2));
+        board.set(1, 2, Cell::Given(3));
+        board.set(2, 0, Cell::Given(4));
+        board.set(2, 1, Cell::Given(6));
+        board.set(2, 2, Cell::Given(7));
+
+        let eliminations = board.pointing_pairs();
+
+        for col in 3..9 {
+            assert!(
+                eliminations.contains(&Elimination { row: 0, col, digit: 5 }),
+                "expected 5 eliminated at (0, {col})"
+            );
+        }
+        for col in 0..3 {
+            assert!(
+                !eliminations.iter().any(|e| e.row == 0 && e.col == col && e.digit == 5),
+                "digit 5 shouldn't be eliminated inside its own source box at (0, {col})"
+            );
+        }
+    }
+
+    #[test]
+    fn pointing_pairs_finds_nothing_on_an_empty_board() {
+        assert!(Board::default().pointing_pairs().is_empty());
+    }
+
+    #[test]
+    fn hidden_pairs_eliminates_other_candidates_from_the_two_confined_cells() {
+        let mut board = Board::default();
+        // Column 0's rows 2-7 are givens using every digit except 5, 6, and
+        // 9, so within that column only rows 0 and 1 can still hold 5 or 6.
+        board.set(2, 0, Cell::Given(1));
+        board.set(3, 0, Cell::Given(2));
+        board.set(4, 0, Cell::Given(3));
+        board.set(5, 0, Cell::Given(4));
+        board.set(6, 0, Cell::Given(7));
+        board.set(7, 0, Cell::Given(8));
+        // Blocks 5 and 6 from row 8's box too, so (8, 0) doesn't also
+        // become a third holder of either digit.
+        board.set(6, 1, Cell::Given(5));
+        board.set(7, 1, Cell::Given(6));
+
+        // Neither (0, 0) nor (1, 0) is reduced to just {5, 6} by itself —
+        // both still list 9 as a candidate too — so this is a hidden pair,
+        // not a plain naked pair.
+        assert_eq!(board.candidates(0, 0), HashSet::from([5, 6, 9]));
+        assert_eq!(board.candidates(1, 0), HashSet::from([5, 6, 9]));
+
+        let eliminations = board.hidden_pairs();
+
+        assert!(eliminations.contains(&Elimination { row: 0, col: 0, digit: 9 }));
+        assert!(eliminations.contains(&Elimination { row: 1, col: 0, digit: 9 }));
+        assert!(!eliminations.iter().any(|e| e.digit == 5 || e.digit == 6));
+    }
+
+    #[test]
+    fn hidden_pairs_finds_nothing_on_an_empty_board() {
+        assert!(Board::default().hidden_pairs().is_empty());
+    }
+
+    #[test]
+    fn x_wing_eliminates_the_digit_from_the_rest_of_the_two_confined_columns() {
+        let mut board = Board::default();
+        // No 9 is placed anywhere, so every untouched cell can still hold
+        // one — except rows 0 and 4, where every column but 2 and 6 is
+        // given some other digit, confining 9 in those rows to a rectangle
+        // at columns 2 and 6. That rectangle rules 9 out of columns 2 and 6
+        // everywhere else on the board.
+        for &row in &[0, 4] {
+            for col in [0, 1, 3, 4, 5, 7, 8] {
+                board.set(row, col, Cell::Given(1));
+            }
+        }
+
+        let eliminations = board.x_wing();
+
+        for row in 0..9 {
+            if row == 0 || row == 4 {
+                continue;
+            }
+            assert!(
+                eliminations.contains(&Elimination { row, col: 2, digit: 9 }),
+                "expected 9 eliminated at ({row}, 2)"
+            );
+            assert!(
+                eliminations.contains(&Elimination { row, col: 6, digit: 9 }),
+                "expected 9 eliminated at ({row}, 6)"
+            );
+        }
+        assert!(!eliminations.iter().any(|e| (e.row == 0 || e.row == 4) && e.digit == 9));
+    }
+
+    #[test]
+    fn x_wing_finds_nothing_on_an_empty_board() {
+        assert!(Board::default().x_wing().is_empty());
+    }
+
+    #[test]
+    fn reset_to_givens_clears_filled_cells_and_notes_but_keeps_givens() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(0, 1, Cell::Filled(3));
+        board.set(0, 2, Cell::Filled(7));
+        board.toggle_note(1, 1, 4);
+
+        board.reset_to_givens();
+
+        assert_eq!(board.get(0, 0), Cell::Given(5));
+        assert_eq!(board.get(0, 1), Cell::Empty);
+        assert_eq!(board.get(0, 2), Cell::Empty);
+        assert!(board.notes(1, 1).is_empty());
+    }
+
+    #[test]
+    fn conflicts_flags_a_duplicate_in_a_row() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(0, 4, Cell::Filled(5));
+
+        let conflicts = board.conflicts();
+        assert!(conflicts.contains(&(0, 0)));
+        assert!(conflicts.contains(&(0, 4)));
+        assert_eq!(conflicts.len(), 2);
+    }
+
+    #[test]
+    fn no_conflicts_on_a_board_with_no_duplicates() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(4, 4, Cell::Filled(5));
+
+        assert!(board.conflicts().is_empty());
+    }
+
+    #[test]
+    fn givens_are_valid_lists_both_positions_of_a_duplicate_given_in_a_row() {
+        let line = "55..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+        let board: Board = line.parse().unwrap();
+
+        let conflicting = board.givens_are_valid().unwrap_err();
+
+        assert_eq!(conflicting, vec![(0, 0), (0, 1)]);
+    }
+
+    #[test]
+    fn givens_are_valid_accepts_a_puzzle_with_no_duplicate_givens() {
+        let board: Board = PUZZLE_LINE.parse().unwrap();
+
+        assert_eq!(board.givens_are_valid(), Ok(()));
+    }
+
+    #[test]
+    fn toggle_note_adds_and_removes_a_candidate() {
+        let mut board = Board::default();
+        board.toggle_note(0, 0, 4);
+        assert!(board.notes(0, 0).contains(&4));
+
+        board.toggle_note(0, 0, 4);
+        assert!(!board.notes(0, 0).contains(&4));
+    }
+
+    #[test]
+    fn set_annotation_tags_and_clears_a_cells_highlight_color() {
+        let mut board = Board::default();
+        assert_eq!(board.annotation(0, 0), None);
+
+        board.set_annotation(0, 0, Some(AnnotationColor::Red));
+        assert_eq!(board.annotation(0, 0), Some(AnnotationColor::Red));
+
+        board.set_annotation(0, 0, None);
+        assert_eq!(board.annotation(0, 0), None);
+    }
+
+    #[test]
+    fn annotations_are_independent_of_a_cells_value_and_notes() {
+        let mut board = Board::default();
+        board.set_annotation(0, 0, Some(AnnotationColor::Blue));
+
+        board.set(0, 0, Cell::Filled(4));
+        board.toggle_note(0, 0, 7);
+
+        assert_eq!(board.annotation(0, 0), Some(AnnotationColor::Blue));
+    }
+
+    #[test]
+    fn setting_a_cell_clears_its_notes() {
+        let mut board = Board::default();
+        board.toggle_note(0, 0, 4);
+        board.set(0, 0, Cell::Filled(4));
+        assert!(board.notes(0, 0).is_empty());
+    }
+
+    #[test]
+    fn setting_a_cell_clears_its_wrong_mark() {
+        let mut board = Board::default();
+        board.mark_wrong(0, 0);
+        assert!(board.is_wrong(0, 0));
+
+        board.set(0, 0, Cell::Filled(4));
+        assert!(!board.is_wrong(0, 0));
+    }
+
+    const SOLVED_GRID: [[u8; 9]; 9] = [
+        [5, 3, 4, 6, 7, 8, 9, 1, 2],
+        [6, 7, 2, 1, 9, 5, 3, 4, 8],
+        [1, 9, 8, 3, 4, 2, 5, 6, 7],
+        [8, 5, 9, 7, 6, 1, 4, 2, 3],
+        [4, 2, 6, 8, 5, 3, 7, 9, 1],
+        [7, 1, 3, 9, 2, 4, 8, 5, 6],
+        [9, 6, 1, 5, 3, 7, 2, 8, 4],
+        [2, 8, 7, 4, 1, 9, 6, 3, 5],
+        [3, 4, 5, 2, 8, 6, 1, 7, 9],
+    ];
+
+    fn board_from_grid(grid: [[u8; 9]; 9]) -> Board {
+        let mut board = Board::default();
+        for (row, values) in grid.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                board.set(row, col, Cell::Given(value));
+            }
+        }
+        board
+    }
+
+    #[test]
+    fn is_solved_is_true_for_a_complete_valid_grid() {
+        assert!(board_from_grid(SOLVED_GRID).is_solved());
+    }
+
+    #[test]
+    fn is_solved_is_false_when_a_cell_is_empty() {
+        let mut board = board_from_grid(SOLVED_GRID);
+        board.set(0, 0, Cell::Empty);
+        assert!(!board.is_solved());
+    }
+
+    #[test]
+    fn is_solved_is_false_when_the_grid_has_a_conflict() {
+        let mut board = board_from_grid(SOLVED_GRID);
+        board.set(0, 0, Cell::Given(3));
+        assert!(!board.is_solved());
+    }
+
+    #[test]
+    fn rotating_a_solved_board_four_times_returns_the_original() {
+        let board = board_from_grid(SOLVED_GRID);
+
+        let mut rotated = board.clone();
+        for _ in 0..4 {
+            rotated = rotated.rotated();
+            assert!(rotated.is_solved());
+        }
+
+        assert_eq!(rotated, board);
+    }
+
+    #[test]
+    fn reflecting_a_solved_board_keeps_it_solved() {
+        assert!(board_from_grid(SOLVED_GRID).reflected().is_solved());
+    }
+
+    #[test]
+    fn swapping_bands_or_stacks_of_a_solved_board_keeps_it_solved() {
+        let board = board_from_grid(SOLVED_GRID);
+        assert!(board.with_bands_swapped(0, 2).is_solved());
+        assert!(board.with_stacks_swapped(0, 2).is_solved());
+    }
+
+    #[test]
+    fn relabeling_a_solved_board_with_a_permutation_preserves_is_solved() {
+        let board = board_from_grid(SOLVED_GRID);
+        // Cycles every digit up by one, wrapping 9 back to 1.
+        let mapping: Vec<u8> = (1..=9).map(|d| if d == 9 { 1 } else { d + 1 }).collect();
+
+        let relabeled = board.relabeled(&mapping);
+
+        assert!(relabeled.is_solved());
+        assert_eq!(relabeled.get(0, 0), Cell::Given(mapping[SOLVED_GRID[0][0] as usize - 1]));
+    }
+
+    const PUZZLE_LINE: &str =
+        "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+
+    #[test]
+    fn parses_and_reserializes_a_known_puzzle_line() {
+        let board: Board = PUZZLE_LINE.parse().unwrap();
+        assert_eq!(board.get(0, 0), Cell::Given(5));
+        assert_eq!(board.get(0, 2), Cell::Empty);
+        assert_eq!(board.get(8, 8), Cell::Given(9));
+        assert_eq!(board.to_str_line(), PUZZLE_LINE);
+    }
+
+    #[test]
+    fn to_ascii_grid_renders_a_boxed_snapshot_of_a_known_board() {
+        let mut board = Board::new(BoardSize::Mini4);
+        board.set(0, 0, Cell::Given(1));
+        board.set(0, 1, Cell::Given(2));
+        board.set(1, 1, Cell::Filled(4));
+        board.set(3, 3, Cell::Given(3));
+
+        let expected = "\
++-----+-----+
+| 1 2 | . . |
+| . 4 | . . |
++-----+-----+
+| . . | . . |
+| . . | . 3 |
++-----+-----+
+";
+
+        assert_eq!(board.to_ascii_grid(), expected);
+    }
+
+    #[test]
+    fn from_str_rejects_the_wrong_length() {
+        assert_eq!("12345".parse::<Board>(), Err(ParseError::WrongLength(5)));
+    }
+
+    #[test]
+    fn from_str_rejects_invalid_characters() {
+        let bad = "x".repeat(81);
+        assert_eq!(bad.parse::<Board>(), Err(ParseError::InvalidChar('x')));
+    }
+
+    #[test]
+    fn a_4x4_board_confines_conflicts_to_its_own_smaller_boxes() {
+        let mut board = Board::new(BoardSize::Mini4);
+        board.set(0, 0, Cell::Given(1));
+        board.set(1, 1, Cell::Filled(1));
+        board.set(2, 2, Cell::Filled(1));
+
+        let conflicts = board.conflicts();
+        assert!(conflicts.contains(&(0, 0)));
+        assert!(conflicts.contains(&(1, 1)));
+        assert!(!conflicts.contains(&(2, 2)));
+    }
+
+    #[test]
+    fn a_6x6_board_uses_2x3_boxes() {
+        let mut board = Board::new(BoardSize::Mini6);
+        board.set(0, 0, Cell::Given(1));
+        board.set(1, 2, Cell::Filled(1));
+        board.set(2, 3, Cell::Filled(1));
+
+        let conflicts = board.conflicts();
+        assert!(conflicts.contains(&(0, 0)));
+        assert!(conflicts.contains(&(1, 2)));
+        assert!(!conflicts.contains(&(2, 3)));
+    }
+
+    #[test]
+    fn a_repeated_diagonal_digit_is_only_flagged_when_the_diagonal_variant_is_on() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(1));
+        board.set(4, 4, Cell::Filled(1));
+        board.set(0, 8, Cell::Given(2));
+        board.set(3, 5, Cell::Filled(2));
+
+        assert!(board.conflicts().is_empty());
+
+        board.set_diagonal(true);
+        let conflicts = board.conflicts();
+        assert!(conflicts.contains(&(0, 0)));
+        assert!(conflicts.contains(&(4, 4)));
+        assert!(conflicts.contains(&(0, 8)));
+        assert!(conflicts.contains(&(3, 5)));
+    }
+
+    #[test]
+    fn hex_digits_round_trip_through_char_conversion_on_a_16x16_board() {
+        for digit in 1..=16u8 {
+            let c = digit_to_char(digit);
+            assert_eq!(char_to_digit(c, BoardSize::Classic16), Some(digit));
+        }
+    }
+
+    #[test]
+    fn hex_letters_are_only_accepted_as_digits_on_the_16x16_board() {
+        assert_eq!(char_to_digit('a', BoardSize::Classic16), Some(10));
+        assert_eq!(char_to_digit('G', BoardSize::Classic16), Some(16));
+        assert_eq!(char_to_digit('a', BoardSize::Classic9), None);
+        assert_eq!(char_to_digit('H', BoardSize::Classic16), None);
+    }
+
+    #[test]
+    fn a_256_char_line_parses_as_a_16x16_board_with_hex_digits() {
+        let line = format!("1G{}", "0".repeat(254));
+        let board: Board = line.parse().unwrap();
+        assert_eq!(board.size(), BoardSize::Classic16);
+        assert_eq!(board.get(0, 0), Cell::Given(1));
+        assert_eq!(board.get(0, 1), Cell::Given(16));
+        assert_eq!(board.get(0, 2), Cell::Empty);
+    }
+}
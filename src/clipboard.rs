@@ -0,0 +1,44 @@
+use std::fmt::Debug;
+
+/// Reads a puzzle string pasted from outside the game. Abstracted away from
+/// `App`'s import handling so tests can feed it a fixed string without a
+/// real system clipboard, and so builds without the `clipboard` feature
+/// still compile against a stub that always reports nothing to paste.
+pub trait Clipboard: Debug {
+    /// The clipboard's current text contents, if any.
+    fn paste(&self) -> Option<String>;
+}
+
+/// The real clipboard, backed by the system's copy/paste buffer. Only
+/// available when the `clipboard` feature is enabled, since it pulls in a
+/// platform clipboard dependency that not every build wants.
+#[cfg(feature = "clipboard")]
+#[derive(Debug, Default)]
+pub struct SystemClipboard;
+
+#[cfg(feature = "clipboard")]
+impl Clipboard for SystemClipboard {
+    fn paste(&self) -> Option<String> {
+        arboard::Clipboard::new().ok()?.get_text().ok()
+    }
+}
+
+/// A no-op stand-in used when the `clipboard` feature is off, so the import
+/// key still does something sensible (reports nothing to paste) rather than
+/// requiring the rest of `App` to know whether the feature is compiled in.
+#[cfg(not(feature = "clipboard"))]
+#[derive(Debug, Default)]
+pub struct SystemClipboard;
+
+#[cfg(not(feature = "clipboard"))]
+impl Clipboard for SystemClipboard {
+    fn paste(&self) -> Option<String> {
+        None
+    }
+}
+
+impl Default for Box<dyn Clipboard> {
+    fn default() -> Self {
+        Box::new(SystemClipboard)
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt::Debug;
+use std::time::Instant;
+
+/// Provides the current time. Abstracted away from `App`'s timer logic so
+/// tests can advance time deterministically instead of sleeping.
+pub trait Clock: Debug {
+    fn now(&self) -> Instant;
+}
+
+/// The real clock, backed by `Instant::now()`.
+#[derive(Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+impl Default for Box<dyn Clock> {
+    fn default() -> Self {
+        Box::new(SystemClock)
+    }
+}
@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ratatui::crossterm::event::KeyCode;
+use serde::{Deserialize, Serialize};
+
+use crate::generator::Difficulty;
+
+/// The logical actions a key press can trigger during play, decoupled from
+/// any particular physical key so they can be remapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Pause,
+    Continue,
+    Solve,
+    ToggleNotes,
+    TogglePeerHighlight,
+    ToggleCoaching,
+    ToggleTimerVisibility,
+    GoToBox,
+    DigitFocus,
+    Annotate,
+    CheckProgress,
+    VerifyNotes,
+    AutoCandidates,
+    FillCellNotes,
+    NakedSingles,
+    HiddenSingles,
+    PointingPairs,
+    HiddenPairs,
+    XWing,
+    JumpToConflict,
+    Undo,
+    UndoAll,
+    Redo,
+    Save,
+    SaveSlot,
+    Hint,
+    PeekSolution,
+    ImportFromClipboard,
+    ExportAscii,
+    ResetToGivens,
+    Restart,
+    Quit,
+}
+
+/// Maps key presses to the `Action` they trigger. Built from a TOML file of
+/// `Action = "key"` overrides, layered on top of sensible defaults.
+#[derive(Debug, Clone)]
+pub struct KeyBindings(HashMap<KeyCode, Action>);
+
+impl KeyBindings {
+    pub fn action_for(&self, key: KeyCode) -> Option<Action> {
+        self.0.get(&key).copied()
+    }
+
+    /// Every current key/action pair, sorted by action so a listing (e.g.
+    /// the help overlay) renders in a stable order regardless of the
+    /// underlying hash map's iteration order.
+    pub fn bindings(&self) -> Vec<(KeyCode, Action)> {
+        let mut pairs: Vec<(KeyCode, Action)> = self.0.iter().map(|(&key, &action)| (key, action)).collect();
+        pairs.sort_by_key(|&(_, action)| action);
+        pairs
+    }
+
+    /// The vim-style `hjkl` movement preset. Also the default.
+    pub fn vim() -> Self {
+        Self::default()
+    }
+
+    /// A WASD movement preset, for players used to that layout from other
+    /// games. Overwrites the default `w` (X-Wing), `a` (auto-candidates)
+    /// and `s` (solve) bindings, same as any other rebinding would.
+    pub fn wasd() -> Self {
+        Self::with_movement_keys('w', 's', 'a', 'd')
+    }
+
+    /// An arrow-keys-only movement preset, leaving every letter key free for
+    /// its default action, for players who'd rather not move with letters
+    /// at all.
+    pub fn arrows_only() -> Self {
+        let mut bindings = default_bindings();
+        bindings.retain(|_, action| !is_movement(*action));
+        bindings.insert(KeyCode::Up, Action::MoveUp);
+        bindings.insert(KeyCode::Down, Action::MoveDown);
+        bindings.insert(KeyCode::Left, Action::MoveLeft);
+        bindings.insert(KeyCode::Right, Action::MoveRight);
+        Self(bindings)
+    }
+
+    /// A numpad-navigation preset. In practice this is just `arrows_only`:
+    /// as the digit handling in `main.rs` notes, a numpad's digits arrive
+    /// as the very same key codes already used to fill cells, so there's no
+    /// way to bind numpad `8`/`2`/`4`/`6` to movement without breaking
+    /// digit entry. The arrow keys are the closest thing a numpad offers to
+    /// a dedicated navigation cluster, and they're always active regardless
+    /// of preset.
+    pub fn numpad() -> Self {
+        Self::arrows_only()
+    }
+
+    fn with_movement_keys(up: char, down: char, left: char, right: char) -> Self {
+        let mut bindings = default_bindings();
+        bindings.retain(|_, action| !is_movement(*action));
+        bindings.insert(KeyCode::Char(up), Action::MoveUp);
+        bindings.insert(KeyCode::Char(down), Action::MoveDown);
+        bindings.insert(KeyCode::Char(left), Action::MoveLeft);
+        bindings.insert(KeyCode::Char(right), Action::MoveRight);
+        Self(bindings)
+    }
+
+    /// Loads bindings from `path`, starting from the named `preset` field
+    /// (falling back to the vim-style default if it's absent or
+    /// unrecognized), then layering any per-action overrides on top. Falls
+    /// back entirely to the default preset if the file is absent or can't
+    /// be parsed.
+    pub fn load(path: &Path) -> Self {
+        let config = fs::read_to_string(path)
+            .ok()
+            .and_then(|toml| toml::from_str::<FileConfig>(&toml).ok())
+            .unwrap_or_default();
+
+        let mut bindings = match config.preset.as_deref() {
+            Some("wasd") => Self::wasd(),
+            Some("arrows") => Self::arrows_only(),
+            Some("numpad") => Self::numpad(),
+            _ => Self::vim(),
+        }
+        .0;
+        for (action, key) in config.overrides {
+            if let Some(key) = parse_key(&key) {
+                bindings.retain(|_, bound_action| *bound_action != action);
+                bindings.insert(key, action);
+            }
+        }
+
+        Self(bindings)
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self(default_bindings())
+    }
+}
+
+fn is_movement(action: Action) -> bool {
+    matches!(
+        action,
+        Action::MoveUp | Action::MoveDown | Action::MoveLeft | Action::MoveRight
+    )
+}
+
+/// The shape of the keybindings TOML file: an optional named preset
+/// (`"wasd"`, `"arrows"`, `"numpad"`, or absent for the vim-style default)
+/// plus any number of `Action = "key"` overrides on top of it.
+#[derive(Default, Deserialize)]
+struct FileConfig {
+    preset: Option<String>,
+    #[serde(flatten)]
+    overrides: HashMap<Action, String>,
+}
+
+fn default_bindings() -> HashMap<KeyCode, Action> {
+    use Action::*;
+    HashMap::from([
+        (KeyCode::Char('k'), MoveUp),
+        (KeyCode::Char('j'), MoveDown),
+        (KeyCode::Char('h'), MoveLeft),
+        (KeyCode::Char('l'), MoveRight),
+        (KeyCode::Char('p'), Pause),
+        (KeyCode::Char('c'), Continue),
+        (KeyCode::Char('s'), Solve),
+        (KeyCode::Char('n'), ToggleNotes),
+        (KeyCode::Char('v'), TogglePeerHighlight),
+        (KeyCode::Char('o'), ToggleCoaching),
+        (KeyCode::Char('t'), ToggleTimerVisibility),
+        (KeyCode::Char('G'), GoToBox),
+        (KeyCode::Char('F'), DigitFocus),
+        (KeyCode::Char('m'), Annotate),
+        (KeyCode::Char('C'), CheckProgress),
+        (KeyCode::Char('V'), VerifyNotes),
+        (KeyCode::Char('a'), AutoCandidates),
+        (KeyCode::Char('f'), FillCellNotes),
+        (KeyCode::Char('N'), NakedSingles),
+        (KeyCode::Char('g'), HiddenSingles),
+        (KeyCode::Char('b'), PointingPairs),
+        (KeyCode::Char('P'), HiddenPairs),
+        (KeyCode::Char('w'), XWing),
+        (KeyCode::Char('X'), JumpToConflict),
+        (KeyCode::Char('u'), Undo),
+        (KeyCode::Char('U'), UndoAll),
+        (KeyCode::Char('r'), Redo),
+        (KeyCode::Char('S'), Save),
+        (KeyCode::Char('K'), SaveSlot),
+        (KeyCode::Char('H'), Hint),
+        (KeyCode::Char('z'), PeekSolution),
+        (KeyCode::Char('I'), ImportFromClipboard),
+        (KeyCode::Char('e'), ExportAscii),
+        (KeyCode::Char('R'), ResetToGivens),
+        (KeyCode::Char('T'), Restart),
+        (KeyCode::Char('q'), Quit),
+    ])
+}
+
+/// How the cursor behaves after a digit is successfully placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AdvanceOnFill {
+    /// The cursor stays put, as it always has.
+    #[default]
+    Off,
+    /// Moves to the next cell in reading order, wrapping past the last
+    /// cell back to the first, regardless of whether it's already filled.
+    NextCell,
+    /// Moves to the next empty cell in reading order, wrapping past the
+    /// last cell back to the first, skipping over already-filled ones.
+    NextEmpty,
+}
+
+/// Small on/off preferences that don't fit the per-action key bindings,
+/// loaded from their own TOML file the same way.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Settings {
+    /// Whether winning or making a disallowed move rings the terminal bell.
+    pub bell_enabled: bool,
+    /// Whether the nine boxes are shaded in an alternating checkerboard
+    /// pattern, to make their boundaries unmistakable even on terminals
+    /// with weak border rendering.
+    #[serde(default)]
+    pub box_shading: bool,
+    /// Whether conflict, peer, and same-digit highlighting are all disabled
+    /// at once, for players who consider them a form of assistance.
+    #[serde(default)]
+    pub expert_rendering: bool,
+    /// Whether (and how) the cursor moves on its own after a digit is
+    /// placed, for players who'd rather not reach for a movement key
+    /// between every entry.
+    #[serde(default)]
+    pub advance_on_fill: AdvanceOnFill,
+    /// Whether givens are underlined, on top of whatever the active theme
+    /// already does to distinguish them, for players on terminals or color
+    /// vision where bold and color alone aren't enough to tell a given
+    /// from their own entry.
+    #[serde(default)]
+    pub high_contrast_givens: bool,
+    /// The difficulty pre-selected on the menu screen at startup.
+    #[serde(default)]
+    pub default_difficulty: Difficulty,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            bell_enabled: true,
+            box_shading: false,
+            expert_rendering: false,
+            advance_on_fill: AdvanceOnFill::Off,
+            high_contrast_givens: false,
+            default_difficulty: Difficulty::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Loads settings from `path`, falling back to the defaults if the file
+    /// is absent or can't be parsed.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|toml| toml::from_str(&toml).ok())
+            .unwrap_or_default()
+    }
+
+    /// Writes settings to `path` as TOML, creating parent directories as
+    /// needed, so in-app changes (e.g. from the settings screen) survive a
+    /// restart.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let toml = toml::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, toml)
+    }
+}
+
+/// The default location of the settings file, under the OS config dir.
+pub fn default_settings_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("settings.toml")
+}
+
+/// Parses a config key string into a `KeyCode`. Only single characters are
+/// supported for now, since every rebindable action is currently bound to
+/// a letter.
+fn parse_key(key: &str) -> Option<KeyCode> {
+    let mut chars = key.chars();
+    let c = chars.next()?;
+    chars.next().is_none().then_some(KeyCode::Char(c))
+}
+
+/// The default location of the keybindings file, under the OS config dir.
+pub fn default_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("keybindings.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_remapped_action_overrides_only_its_default_key() {
+        let dir = std::env::temp_dir().join(format!("rusuku-config-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("keybindings.toml");
+        fs::write(&path, "Quit = \"x\"\n").unwrap();
+
+        let bindings = KeyBindings::load(&path);
+
+        assert_eq!(bindings.action_for(KeyCode::Char('x')), Some(Action::Quit));
+        assert_eq!(bindings.action_for(KeyCode::Char('q')), None);
+        assert_eq!(bindings.action_for(KeyCode::Char('k')), Some(Action::MoveUp));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn a_missing_file_falls_back_to_every_default() {
+        let path = std::env::temp_dir().join("rusuku-config-test-missing-file.toml");
+
+        let bindings = KeyBindings::load(&path);
+
+        assert_eq!(bindings.action_for(KeyCode::Char('q')), Some(Action::Quit));
+        assert_eq!(bindings.action_for(KeyCode::Char('H')), Some(Action::Hint));
+    }
+
+    #[test]
+    fn the_wasd_preset_maps_w_to_move_up_and_d_to_move_right() {
+        let bindings = KeyBindings::wasd();
+
+        assert_eq!(bindings.action_for(KeyCode::Char('w')), Some(Action::MoveUp));
+        assert_eq!(bindings.action_for(KeyCode::Char('d')), Some(Action::MoveRight));
+        assert_eq!(bindings.action_for(KeyCode::Char('s')), Some(Action::MoveDown));
+        assert_eq!(bindings.action_for(KeyCode::Char('a')), Some(Action::MoveLeft));
+    }
+
+    #[test]
+    fn a_preset_named_in_the_config_file_is_layered_with_overrides() {
+        let dir = std::env::temp_dir().join(format!("rusuku-config-preset-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("keybindings.toml");
+        fs::write(&path, "preset = \"wasd\"\nQuit = \"x\"\n").unwrap();
+
+        let bindings = KeyBindings::load(&path);
+
+        assert_eq!(bindings.action_for(KeyCode::Char('w')), Some(Action::MoveUp));
+        assert_eq!(bindings.action_for(KeyCode::Char('x')), Some(Action::Quit));
+        assert_eq!(bindings.action_for(KeyCode::Char('k')), None);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::Debug;
+use std::io::{self, Write};
+
+/// Requests the game's audible cues. Abstracted away from `App`'s win and
+/// mistake handling so tests can assert a bell was requested without
+/// actually ringing the terminal during a test run.
+pub trait Feedback: Debug {
+    /// Requests the terminal bell (`\x07`).
+    fn bell(&self);
+}
+
+/// The real sink, backed by the terminal bell escape sequence.
+#[derive(Debug, Default)]
+pub struct TerminalBell;
+
+impl Feedback for TerminalBell {
+    fn bell(&self) {
+        print!("\x07");
+        let _ = io::stdout().flush();
+    }
+}
+
+impl Default for Box<dyn Feedback> {
+    fn default() -> Self {
+        Box::new(TerminalBell)
+    }
+}
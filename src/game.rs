@@ -0,0 +1,161 @@
+use crate::board::{Board, Cell};
+
+/// The puzzle session's core state: the board, the selected cell, the
+/// move/mistake counters, and the solution used to judge entries. Kept
+/// separate from `App` so this logic is testable without a `Frame`; `App`
+/// owns one `Game` and layers timer, input, and rendering concerns on top.
+#[derive(Debug, Default)]
+pub struct Game {
+    pub(crate) board: Board,
+    pub(crate) cursor: (usize, usize),
+    /// How many digits have been placed or cleared this game, for players
+    /// tracking their efficiency. Cursor movement and notes don't count.
+    pub(crate) moves: u32,
+    /// How many entries this game contradicted the puzzle's solution,
+    /// whether or not they were actually placed (a strict-mode rejection
+    /// still counts). Feeds into `score::compute`.
+    pub(crate) mistakes: u32,
+    /// The current puzzle's solution, computed once when the game starts
+    /// so strict mode can check entries without re-solving on every key
+    /// press. `None` before a game has started.
+    pub(crate) solution: Option<Board>,
+}
+
+impl Game {
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn board_mut(&mut self) -> &mut Board {
+        &mut self.board
+    }
+
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    pub fn set_cursor(&mut self, cursor: (usize, usize)) {
+        self.cursor = cursor;
+    }
+
+    pub fn moves(&self) -> u32 {
+        self.moves
+    }
+
+    pub fn add_move(&mut self) {
+        self.moves += 1;
+    }
+
+    pub fn reset_moves(&mut self) {
+        self.moves = 0;
+    }
+
+    pub fn mistakes(&self) -> u32 {
+        self.mistakes
+    }
+
+    pub fn add_mistake(&mut self) {
+        self.mistakes += 1;
+    }
+
+    pub fn reset_mistakes(&mut self) {
+        self.mistakes = 0;
+    }
+
+    pub fn solution(&self) -> Option<&Board> {
+        self.solution.as_ref()
+    }
+
+    pub fn set_solution(&mut self, solution: Option<Board>) {
+        self.solution = solution;
+    }
+
+    /// Places `digit` at the cursor (`0` clears it), unless the cursor is on
+    /// a given. Returns the cell's previous state for the caller's undo
+    /// stack, or `None` if the cell couldn't be changed.
+    pub fn fill(&mut self, digit: u8) -> Option<Cell> {
+        let (row, col) = self.cursor;
+        if matches!(self.board.get(row, col), Cell::Given(_)) {
+            return None;
+        }
+        let prev = self.board.get(row, col);
+        let cell = if digit == 0 { Cell::Empty } else { Cell::Filled(digit) };
+        self.board.set(row, col, cell);
+        self.add_move();
+        Some(prev)
+    }
+
+    /// Clears the selected cell, per `fill(0)`.
+    pub fn clear(&mut self) -> Option<Cell> {
+        self.fill(0)
+    }
+
+    /// Moves the cursor by `(d_row, d_col)`, clamped to the board's edges.
+    pub fn move_cursor(&mut self, d_row: isize, d_col: isize) {
+        let last = self.board.size().side() as isize - 1;
+        let (row, col) = self.cursor;
+        let row = (row as isize + d_row).clamp(0, last) as usize;
+        let col = (col as isize + d_col).clamp(0, last) as usize;
+        self.cursor = (row, col);
+    }
+
+    /// Whether the board is completely and correctly filled in.
+    pub fn is_won(&self) -> bool {
+        self.board.is_solved()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_places_a_digit_and_zero_clears_it() {
+        let mut game = Game::default();
+
+        assert_eq!(game.fill(5), Some(Cell::Empty));
+        assert_eq!(game.board().get(0, 0), Cell::Filled(5));
+        assert_eq!(game.moves(), 1);
+
+        assert_eq!(game.clear(), Some(Cell::Filled(5)));
+        assert_eq!(game.board().get(0, 0), Cell::Empty);
+        assert_eq!(game.moves(), 2);
+    }
+
+    #[test]
+    fn fill_does_not_overwrite_a_given() {
+        let mut game = Game::default();
+        game.board_mut().set(0, 0, Cell::Given(7));
+
+        assert_eq!(game.fill(3), None);
+        assert_eq!(game.board().get(0, 0), Cell::Given(7));
+        assert_eq!(game.moves(), 0);
+    }
+
+    #[test]
+    fn move_cursor_clamps_to_the_boards_edges() {
+        let mut game = Game::default();
+
+        game.move_cursor(-1, -1);
+        assert_eq!(game.cursor(), (0, 0));
+
+        game.set_cursor((8, 8));
+        game.move_cursor(1, 1);
+        assert_eq!(game.cursor(), (8, 8));
+    }
+
+    #[test]
+    fn is_won_is_true_only_once_the_board_is_solved() {
+        let mut game = Game::default();
+        assert!(!game.is_won());
+
+        for row in 0..9 {
+            for col in 0..9 {
+                let digit = ((row * 3 + row / 3 + col) % 9) as u8 + 1;
+                game.board_mut().set(row, col, Cell::Filled(digit));
+            }
+        }
+
+        assert!(game.is_won());
+    }
+}
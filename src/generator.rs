@@ -0,0 +1,444 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, RngExt, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::board::{Board, BoardSize, Cell};
+use crate::solver;
+
+/// How many clues a generated puzzle keeps.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Medium,
+    Hard,
+    /// Not currently offered as a generation target from the menu; reached
+    /// only as `rating::rate`'s classification of a puzzle that needs
+    /// hidden pairs (or harder) to finish. Exhaustively matched here anyway,
+    /// with a given count sparser than `Hard`'s, so the enum stays honest if
+    /// that changes.
+    Expert,
+    /// A generation target (not a rating): digs towards the fewest givens
+    /// `remove_cells`'s uniqueness-preserving budget can reach, for players
+    /// chasing something close to the 17-clue theoretical minimum on a
+    /// classic 9x9 board.
+    Extreme,
+}
+
+impl Difficulty {
+    pub(crate) fn givens(self, size: BoardSize) -> usize {
+        match (self, size) {
+            (Difficulty::Easy, BoardSize::Mini4) => 10,
+            (Difficulty::Medium, BoardSize::Mini4) => 8,
+            (Difficulty::Hard, BoardSize::Mini4) => 6,
+            (Difficulty::Expert, BoardSize::Mini4) => 5,
+            (Difficulty::Extreme, BoardSize::Mini4) => 4,
+            (Difficulty::Easy, BoardSize::Mini6) => 22,
+            (Difficulty::Medium, BoardSize::Mini6) => 18,
+            (Difficulty::Hard, BoardSize::Mini6) => 14,
+            (Difficulty::Expert, BoardSize::Mini6) => 12,
+            (Difficulty::Extreme, BoardSize::Mini6) => 8,
+            (Difficulty::Easy, BoardSize::Classic9) => 40,
+            (Difficulty::Medium, BoardSize::Classic9) => 32,
+            (Difficulty::Hard, BoardSize::Classic9) => 26,
+            (Difficulty::Expert, BoardSize::Classic9) => 22,
+            (Difficulty::Extreme, BoardSize::Classic9) => 17,
+            (Difficulty::Easy, BoardSize::Classic16) => 140,
+            (Difficulty::Medium, BoardSize::Classic16) => 110,
+            (Difficulty::Hard, BoardSize::Classic16) => 90,
+            (Difficulty::Expert, BoardSize::Classic16) => 75,
+            (Difficulty::Extreme, BoardSize::Classic16) => 60,
+        }
+    }
+
+    /// How much a puzzle at this difficulty multiplies a completed game's
+    /// base score, since a harder puzzle solved in the same time, with the
+    /// same hints and mistakes, deserves more credit.
+    pub(crate) fn score_multiplier(self) -> u32 {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Medium => 2,
+            Difficulty::Hard => 3,
+            Difficulty::Expert => 4,
+            Difficulty::Extreme => 5,
+        }
+    }
+}
+
+/// Which reflection or rotation of the grid a puzzle's givens should keep,
+/// purely for how the finished puzzle looks; it has no effect on
+/// difficulty.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    /// No constraint on which cells are removed.
+    #[allow(dead_code)]
+    None,
+    /// A given at `(row, col)` implies one at the 180-degree rotation,
+    /// `(side - 1 - row, side - 1 - col)`.
+    #[default]
+    Rotational180,
+    /// A given at `(row, col)` implies one at its mirror across the
+    /// horizontal midline, `(side - 1 - row, col)`.
+    #[allow(dead_code)]
+    Horizontal,
+}
+
+impl Symmetry {
+    /// The cell that must be removed alongside `(row, col)` to preserve
+    /// this symmetry, on a board of the given `side`. Equal to `(row, col)`
+    /// itself for `None`, and for the exact center of an odd-sized board.
+    fn mirror(self, (row, col): (usize, usize), side: usize) -> (usize, usize) {
+        match self {
+            Symmetry::None => (row, col),
+            Symmetry::Rotational180 => (side - 1 - row, side - 1 - col),
+            Symmetry::Horizontal => (side - 1 - row, col),
+        }
+    }
+}
+
+/// Generates a puzzle of `size` at the requested `difficulty`: a fully
+/// solved grid with cells removed in mirrored pairs per `symmetry`, keeping
+/// only removals that leave the puzzle with a unique solution. When
+/// `diagonal` is set, the two main diagonals must also hold every digit
+/// exactly once, and that constraint is honored throughout generation.
+pub fn generate(difficulty: Difficulty, size: BoardSize, symmetry: Symmetry, diagonal: bool) -> Board {
+    generate_with_rng(difficulty, size, symmetry, diagonal, &mut rand::rng())
+}
+
+/// Generates the same puzzle every time for a given `seed`, at the classic
+/// 9x9 size with rotational symmetry, so everyone doing a daily challenge
+/// gets an identical board.
+pub fn generate_seeded(difficulty: Difficulty, seed: u64) -> Board {
+    let mut rng = StdRng::seed_from_u64(seed);
+    generate_with_rng(difficulty, BoardSize::default(), Symmetry::default(), false, &mut rng)
+}
+
+fn generate_with_rng(
+    difficulty: Difficulty,
+    size: BoardSize,
+    symmetry: Symmetry,
+    diagonal: bool,
+    rng: &mut impl Rng,
+) -> Board {
+    let solved = generate_solved_grid(size, diagonal, rng);
+    let solved = diversify(solved, rng);
+    let puzzle = remove_cells(solved, difficulty.givens(size), symmetry, rng);
+    as_givens(puzzle)
+}
+
+/// Applies a random combination of `Board`'s shape-preserving
+/// transformations to `solved`, so puzzles built from the same first-row
+/// shuffle still end up structurally distinct: a rotation, a reflection, a
+/// swapped band pair, a swapped stack pair, and a full digit relabeling,
+/// each chosen at random. Every transformation maps a solved board to
+/// another solved board, so this can't turn a valid grid invalid.
+///
+/// Rotation is skipped unless boxes are square (`box_rows == box_cols`):
+/// swapping the row and column axes turns a `box_rows x box_cols` box into
+/// a `box_cols x box_rows` one, which only lines back up with the fixed
+/// box grid when the two are equal. Band and stack swaps are skipped for
+/// the X-Sudoku diagonal variant: they reorder whole row/column groups,
+/// which scatters the cells that used to sit on the main diagonals and
+/// would break the extra diagonal constraint that reflection and
+/// relabeling both leave intact.
+fn diversify(solved: Board, rng: &mut impl Rng) -> Board {
+    let side = solved.size().side();
+    let (box_rows, box_cols) = solved.size().box_dims();
+
+    let mut solved = solved;
+    if box_rows == box_cols {
+        for _ in 0..rng.random_range(0..4) {
+            solved = solved.rotated();
+        }
+    }
+    if rng.random_bool(0.5) {
+        solved = solved.reflected();
+    }
+
+    if !solved.is_diagonal() {
+        let bands = side / box_rows;
+        if bands > 1 {
+            solved = solved.with_bands_swapped(rng.random_range(0..bands), rng.random_range(0..bands));
+        }
+        let stacks = side / box_cols;
+        if stacks > 1 {
+            solved = solved.with_stacks_swapped(rng.random_range(0..stacks), rng.random_range(0..stacks));
+        }
+    }
+
+    let mut mapping: Vec<u8> = (1..=side as u8).collect();
+    mapping.shuffle(rng);
+    solved.relabeled(&mapping)
+}
+
+/// Marks every remaining digit as a `Given`, since the digits left after
+/// removal are the puzzle's clues, not player entries.
+fn as_givens(board: Board) -> Board {
+    let side = board.size().side();
+    let mut givens = Board::new(board.size());
+    givens.set_diagonal(board.is_diagonal());
+    for row in 0..side {
+        for col in 0..side {
+            if let Some(digit) = board.get(row, col).digit() {
+                givens.set(row, col, Cell::Given(digit));
+            }
+        }
+    }
+    givens
+}
+
+fn generate_solved_grid(size: BoardSize, diagonal: bool, rng: &mut impl Rng) -> Board {
+    let side = size.side();
+    let mut first_row: Vec<u8> = (1..=side as u8).collect();
+    first_row.shuffle(rng);
+
+    let mut board = Board::new(size);
+    board.set_diagonal(diagonal);
+    for (col, &digit) in first_row.iter().enumerate() {
+        board.set(0, col, Cell::Given(digit));
+    }
+
+    solver::solve(&board).expect("a shuffled first row is always completable")
+}
+
+/// How many uniqueness-check attempts `remove_cells` will make before giving
+/// up and returning the sparsest puzzle it's found so far, in case an
+/// unlucky shuffle makes every remaining cell a dead end for the target
+/// given-count. Generous enough that a normal run finishes long before
+/// hitting it, on any supported board size.
+const REMOVAL_BUDGET: usize = 500;
+
+fn remove_cells(solved: Board, target_givens: usize, symmetry: Symmetry, rng: &mut impl Rng) -> Board {
+    remove_cells_with_budget(solved, target_givens, symmetry, rng, REMOVAL_BUDGET)
+}
+
+/// Removes cells (in mirrored pairs per `symmetry`) from `solved`, keeping
+/// only removals that leave the puzzle with a unique solution, until no
+/// further pair can be removed without breaking that. Returns the resulting
+/// puzzle along with how many uniqueness checks it took, so a caller
+/// digging towards a target below what one pass can locally reach knows how
+/// much of its budget remains for another attempt.
+fn dig_to_local_minimum(
+    solved: &Board,
+    target_givens: usize,
+    symmetry: Symmetry,
+    rng: &mut impl Rng,
+    budget: usize,
+) -> (Board, usize) {
+    let side = solved.size().side();
+    let mut remaining_givens = side * side;
+    let mut puzzle = solved.clone();
+    let mut attempts = 0;
+
+    loop {
+        if remaining_givens <= target_givens || attempts >= budget {
+            break;
+        }
+
+        let mut positions: Vec<(usize, usize)> = (0..side)
+            .flat_map(|row| (0..side).map(move |col| (row, col)))
+            .filter(|&(row, col)| puzzle.get(row, col) != Cell::Empty)
+            .collect();
+        positions.shuffle(rng);
+
+        let mut attempted: HashSet<(usize, usize)> = HashSet::new();
+        let mut removed_this_pass = false;
+
+        for (row, col) in positions {
+            if remaining_givens <= target_givens || attempts >= budget {
+                break;
+            }
+            if !attempted.insert((row, col)) || puzzle.get(row, col) == Cell::Empty {
+                continue;
+            }
+            let mirror = symmetry.mirror((row, col), side);
+            attempted.insert(mirror);
+
+            let cells: Vec<(usize, usize)> = if mirror == (row, col) {
+                vec![(row, col)]
+            } else {
+                vec![(row, col), mirror]
+            };
+            if cells.iter().any(|&(r, c)| puzzle.get(r, c) == Cell::Empty) {
+                continue;
+            }
+
+            let removed: Vec<Cell> = cells.iter().map(|&(r, c)| puzzle.get(r, c)).collect();
+            for &(r, c) in &cells {
+                puzzle.set(r, c, Cell::Empty);
+            }
+
+            attempts += 1;
+            if solver::count_solutions(&puzzle, 2) == 1 {
+                remaining_givens -= cells.len();
+                removed_this_pass = true;
+            } else {
+                for (&(r, c), cell) in cells.iter().zip(removed) {
+                    puzzle.set(r, c, cell);
+                }
+            }
+        }
+
+        if !removed_this_pass {
+            break;
+        }
+    }
+
+    (puzzle, attempts)
+}
+
+/// Digs `solved` down towards `target_givens`, restarting from a fresh
+/// shuffle whenever a dig converges on a local minimum above the target,
+/// and keeping the sparsest puzzle found. A single dig alone can stall well
+/// short of an aggressive target once a few unlucky removals block the
+/// rest, so restarting is what lets a low target (like `Difficulty::Extreme`'s)
+/// actually dig deeper than a comfortably reachable one (like `Hard`'s).
+/// Stops once `budget` uniqueness checks have been spent even if the target
+/// hasn't been reached, returning the sparsest valid puzzle found so far
+/// rather than digging forever.
+/// How many times `remove_cells_with_budget` will restart digging from a
+/// fresh shuffle after converging on a local minimum above the target.
+/// Kept small since each restart re-runs a full round of (increasingly
+/// expensive, on a sparser board) uniqueness checks.
+const MAX_DIG_RESTARTS: usize = 3;
+
+fn remove_cells_with_budget(
+    solved: Board,
+    target_givens: usize,
+    symmetry: Symmetry,
+    rng: &mut impl Rng,
+    budget: usize,
+) -> Board {
+    let side = solved.size().side();
+    let mut best = solved.clone();
+    let mut best_givens = side * side;
+    let mut attempts = 0;
+
+    for _ in 0..MAX_DIG_RESTARTS {
+        if best_givens <= target_givens || attempts >= budget {
+            break;
+        }
+
+        let (puzzle, used) = dig_to_local_minimum(&solved, target_givens, symmetry, rng, budget - attempts);
+        attempts += used;
+
+        let given_count = (0..side)
+            .flat_map(|row| (0..side).map(move |col| (row, col)))
+            .filter(|&(row, col)| puzzle.get(row, col) != Cell::Empty)
+            .count();
+        if given_count < best_givens {
+            best_givens = given_count;
+            best = puzzle;
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZES: [BoardSize; 3] = [BoardSize::Mini4, BoardSize::Mini6, BoardSize::Classic9];
+
+    #[test]
+    fn generated_puzzles_have_a_unique_solution() {
+        for size in SIZES {
+            for difficulty in
+                [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard, Difficulty::Expert, Difficulty::Extreme]
+            {
+                let puzzle = generate(difficulty, size, Symmetry::default(), false);
+                assert_eq!(solver::count_solutions(&puzzle, 2), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn extreme_digs_below_hards_given_count_while_staying_unique() {
+        let hard = generate_seeded(Difficulty::Hard, 11);
+        let extreme = generate_seeded(Difficulty::Extreme, 11);
+
+        assert_eq!(solver::count_solutions(&extreme, 2), 1);
+        assert!(extreme.given_count() < hard.given_count());
+    }
+
+    #[test]
+    fn generated_puzzles_mark_every_clue_as_a_given() {
+        for size in SIZES {
+            let puzzle = generate(Difficulty::Medium, size, Symmetry::default(), false);
+            let side = size.side();
+            for row in 0..side {
+                for col in 0..side {
+                    if puzzle.get(row, col) != Cell::Empty {
+                        assert!(matches!(puzzle.get(row, col), Cell::Given(_)));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generate_seeded_is_deterministic_for_the_same_seed() {
+        let first = generate_seeded(Difficulty::Medium, 42);
+        let second = generate_seeded(Difficulty::Medium, 42);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn generate_seeded_generally_differs_across_seeds() {
+        let first = generate_seeded(Difficulty::Medium, 1);
+        let second = generate_seeded(Difficulty::Medium, 2);
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn rotational180_symmetry_pairs_every_given_with_its_180_degree_rotation() {
+        let size = BoardSize::Classic9;
+        let side = size.side();
+        let puzzle = generate(Difficulty::Medium, size, Symmetry::Rotational180, false);
+
+        for row in 0..side {
+            for col in 0..side {
+                let (mirror_row, mirror_col) = (side - 1 - row, side - 1 - col);
+                if (row, col) == (mirror_row, mirror_col) {
+                    continue;
+                }
+                assert_eq!(
+                    puzzle.get(row, col) != Cell::Empty,
+                    puzzle.get(mirror_row, mirror_col) != Cell::Empty,
+                    "given at ({row}, {col}) has no matching given at ({mirror_row}, {mirror_col})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn diagonal_puzzles_are_generated_with_a_unique_solution_honoring_the_diagonals() {
+        for size in SIZES {
+            let puzzle = generate(Difficulty::Medium, size, Symmetry::default(), true);
+            assert!(puzzle.is_diagonal());
+            assert_eq!(solver::count_solutions(&puzzle, 2), 1);
+        }
+    }
+
+    #[test]
+    fn a_tight_budget_stops_short_of_the_target_but_stays_unique() {
+        let size = BoardSize::Classic9;
+        let mut rng = StdRng::seed_from_u64(7);
+        let solved = generate_solved_grid(size, false, &mut rng);
+
+        let puzzle = remove_cells_with_budget(solved, Difficulty::Hard.givens(size), Symmetry::default(), &mut rng, 3);
+
+        // A budget of 3 attempts can remove at most 6 cells (mirrored pairs),
+        // nowhere near hard's target of 26 givens out of 81.
+        let side = size.side();
+        let givens = (0..side).flat_map(|row| (0..side).map(move |col| (row, col)))
+            .filter(|&(row, col)| puzzle.get(row, col) != Cell::Empty)
+            .count();
+        assert!(givens > Difficulty::Hard.givens(size));
+        assert_eq!(solver::count_solutions(&puzzle, 2), 1);
+    }
+}
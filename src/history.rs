@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use crate::board::Cell;
+
+/// A snapshot of a single cell's digit and pencil marks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellState {
+    pub cell: Cell,
+    pub notes: HashSet<u8>,
+}
+
+/// A single undoable board edit.
+#[derive(Debug, Clone)]
+pub struct Move {
+    pub row: usize,
+    pub col: usize,
+    pub prev: CellState,
+    pub next: CellState,
+}
+
+/// One or more `Move`s that undo or redo together as a single atomic step,
+/// e.g. every cell an auto-candidates sweep touched.
+#[derive(Debug, Clone)]
+pub struct MoveGroup(pub Vec<Move>);
+
+impl MoveGroup {
+    /// Wraps a single move in a group of its own, for the common case of an
+    /// edit that only ever touches one cell.
+    pub fn single(mv: Move) -> Self {
+        Self(vec![mv])
+    }
+}
+
+/// Undo/redo history for board edits. Recording a new move (or group)
+/// clears the redo stack, matching standard editor undo semantics.
+#[derive(Debug, Default)]
+pub struct UndoStack {
+    undo: Vec<MoveGroup>,
+    redo: Vec<MoveGroup>,
+}
+
+impl UndoStack {
+    /// Records a single-cell move, clearing any redo history made stale by
+    /// it.
+    pub fn push(&mut self, mv: Move) {
+        self.push_group(MoveGroup::single(mv));
+    }
+
+    /// Records a group of moves that undo and redo together, clearing any
+    /// redo history made stale by it.
+    pub fn push_group(&mut self, group: MoveGroup) {
+        self.undo.push(group);
+        self.redo.clear();
+    }
+
+    /// Pops the most recent move (or group) and makes it available to
+    /// `redo`.
+    pub fn undo(&mut self) -> Option<MoveGroup> {
+        let group = self.undo.pop()?;
+        self.redo.push(group.clone());
+        Some(group)
+    }
+
+    /// Pops the most recently undone move (or group) and makes it available
+    /// to `undo`.
+    pub fn redo(&mut self) -> Option<MoveGroup> {
+        let group = self.redo.pop()?;
+        self.undo.push(group.clone());
+        Some(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mv(row: usize, prev: u8, next: u8) -> Move {
+        Move {
+            row,
+            col: 0,
+            prev: CellState {
+                cell: Cell::Filled(prev),
+                notes: HashSet::new(),
+            },
+            next: CellState {
+                cell: Cell::Filled(next),
+                notes: HashSet::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn undo_then_redo_round_trips() {
+        let mut stack = UndoStack::default();
+        stack.push(mv(0, 0, 1));
+
+        let undone = stack.undo().unwrap();
+        assert_eq!(undone.0[0].prev.cell, Cell::Filled(0));
+
+        let redone = stack.redo().unwrap();
+        assert_eq!(redone.0[0].next.cell, Cell::Filled(1));
+    }
+
+    #[test]
+    fn pushing_after_an_undo_clears_redo_history() {
+        let mut stack = UndoStack::default();
+        stack.push(mv(0, 0, 1));
+        stack.undo();
+        stack.push(mv(1, 0, 2));
+
+        assert!(stack.redo().is_none());
+    }
+
+    #[test]
+    fn a_group_of_moves_undoes_and_redoes_as_one_step() {
+        let mut stack = UndoStack::default();
+        stack.push_group(MoveGroup(vec![mv(0, 0, 1), mv(1, 0, 2)]));
+
+        let undone = stack.undo().unwrap();
+        assert_eq!(undone.0.len(), 2);
+
+        let redone = stack.redo().unwrap();
+        assert_eq!(redone.0.len(), 2);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::board::Board;
+use crate::generator::Difficulty;
+
+/// One puzzle from the bundled library, named and rated ahead of time so
+/// the library screen can list them without generating or solving
+/// anything. The puzzle itself is only parsed into a `Board` once chosen.
+#[derive(Debug, Clone)]
+pub struct NamedPuzzle {
+    pub name: String,
+    pub difficulty: Difficulty,
+    line: String,
+}
+
+impl NamedPuzzle {
+    /// Parses this entry's puzzle line into a board, the same way a puzzle
+    /// loaded from a file is.
+    pub fn board(&self) -> Result<Board, crate::board::ParseError> {
+        self.line.parse()
+    }
+}
+
+/// The library shipped with the game, embedded at compile time so it's
+/// available without any files on disk.
+const LIBRARY: &str = include_str!("../data/library.txt");
+
+/// Parses the bundled library into puzzles, skipping blank lines, `#`
+/// comments, and any entry that isn't `name|difficulty|puzzle`, so one bad
+/// line doesn't take the rest of the library down with it.
+pub fn load() -> Vec<NamedPuzzle> {
+    LIBRARY
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '|');
+            let name = fields.next()?.trim();
+            let difficulty = fields.next()?.trim();
+            let puzzle = fields.next()?.trim();
+            if name.is_empty() || puzzle.is_empty() {
+                return None;
+            }
+            let difficulty = match difficulty {
+                "Easy" => Difficulty::Easy,
+                "Medium" => Difficulty::Medium,
+                "Hard" => Difficulty::Hard,
+                "Expert" => Difficulty::Expert,
+                "Extreme" => Difficulty::Extreme,
+                _ => return None,
+            };
+            Some(NamedPuzzle { name: name.to_string(), difficulty, line: puzzle.to_string() })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_bundled_library_has_at_least_one_valid_puzzle_per_difficulty() {
+        let puzzles = load();
+        assert!(!puzzles.is_empty());
+
+        for difficulty in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard, Difficulty::Extreme] {
+            let puzzle = puzzles
+                .iter()
+                .find(|puzzle| puzzle.difficulty == difficulty)
+                .unwrap_or_else(|| panic!("no bundled puzzle for {difficulty:?}"));
+            let board = puzzle.board().unwrap_or_else(|err| panic!("{}: {err}", puzzle.name));
+            assert!(board.given_count() > 0);
+        }
+    }
+}
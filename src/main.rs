@@ -1,334 +1,6131 @@
 use ratatui::{
     buffer::Buffer,
-    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    crossterm::event::{
+        self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+    },
     layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
-    style::Stylize,
+    style::{Color, Modifier, Style, Stylize},
     symbols::{self, border},
-    text::Text,
-    widgets::{block::Title, Block, Borders, Paragraph, Widget},
-    Frame,
+    text::{Line, Span, Text},
+    widgets::{block::Title, Block, Borders, Clear, Paragraph, Widget},
+    Frame, Terminal,
 };
 use std::{
     io,
-    time::{Duration, Instant},
+    path::PathBuf,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+mod board;
+mod clipboard;
+mod clock;
+mod config;
+mod feedback;
+mod game;
+mod generator;
+mod history;
+mod library;
+mod persistence;
+mod rating;
+mod score;
+mod solver;
+mod stats;
+mod theme;
 mod tui;
 
+use board::{char_to_digit, digit_to_char, AnnotationColor, Board, BoardSize, Cell, Elimination};
+use clipboard::Clipboard;
+use clock::Clock;
+use config::{Action, AdvanceOnFill, KeyBindings, Settings};
+use feedback::Feedback;
+use game::Game;
+use generator::{Difficulty, Symmetry};
+use history::{CellState, Move, MoveGroup, UndoStack};
+use theme::{Theme, ThemeName};
+use tui::Restore;
+
 fn main() -> io::Result<()> {
+    let mut app = match std::env::args().nth(1) {
+        Some(path) => match persistence::load_puzzle(std::path::Path::new(&path)) {
+            Ok(board) => match board.givens_are_valid() {
+                Ok(()) => {
+                    warn_if_not_uniquely_solvable(&path, &board);
+                    App::from_puzzle(board)
+                }
+                Err(conflicting) => {
+                    eprintln!("failed to load puzzle from {path}: givens conflict at {conflicting:?}");
+                    std::process::exit(1);
+                }
+            },
+            Err(err) => {
+                eprintln!("failed to load puzzle from {path}: {err}");
+                std::process::exit(1);
+            }
+        },
+        None => App::load_or_default(),
+    };
+    tui::install_panic_hook();
     let mut terminal = tui::init()?;
-    let app_result = App::default().run(&mut terminal);
+    let app_result = app.run(&mut terminal);
     tui::restore()?;
     app_result
 }
 
+/// Warns on stderr if `board`, imported from `path`, doesn't have exactly
+/// one solution, so a malformed or under-specified puzzle doesn't fail
+/// silently. A cap of 2 is enough to tell unique from multiple without
+/// paying for a full solution count.
+fn warn_if_not_uniquely_solvable(path: &str, board: &Board) {
+    match solver::count_solutions(board, 2) {
+        0 => eprintln!("warning: puzzle from {path} has no solution"),
+        1 => {}
+        _ => eprintln!("warning: puzzle from {path} has more than one solution"),
+    }
+}
+
+/// A seed that's the same for every player on a given UTC day, and changes
+/// once every 24 hours, for the daily challenge.
+fn daily_seed() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_secs() / (24 * 60 * 60))
+        .unwrap_or(0)
+}
+
+/// The difficulty choices offered on the menu screen, in display order.
+/// `Difficulty::Expert` is excluded since it's reached only as a rating
+/// classification, never chosen as a generation target.
+const DIFFICULTIES: [Difficulty; 4] =
+    [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard, Difficulty::Extreme];
+
+/// How many rows the settings screen lists: theme, peer highlighting, auto
+/// notes, advance-on-fill, the bell, timer visibility, and the default
+/// difficulty.
+const SETTINGS_ROWS: usize = 7;
+
+/// The board size choices offered on the menu screen, in display order.
+/// Classic9 comes first so the default selection matches the board's own
+/// default size.
+const VARIANTS: [BoardSize; 4] = [BoardSize::Classic9, BoardSize::Mini6, BoardSize::Mini4, BoardSize::Classic16];
+
+/// The narrowest terminal width the playing screen can render into without
+/// clipping the header or grid.
+const MIN_TERMINAL_WIDTH: u16 = 55;
+
+/// The shortest terminal height the playing screen can render into without
+/// clipping the header or grid.
+const MIN_TERMINAL_HEIGHT: u16 = 18;
+
+/// Whether opening the help overlay pauses the timer.
+const PAUSE_TIMER_ON_HELP: bool = true;
+
+/// How long a countdown-mode game gives the player before time runs out.
+const DEFAULT_TIME_LIMIT: Duration = Duration::from_secs(600);
+
+/// Below this much remaining time, the header renders it in the theme's
+/// `wrong` style to warn the player it's about to run out.
+const LOW_TIME_WARNING: Duration = Duration::from_secs(60);
+
+/// How long a given's border flashes red after a rejected overwrite.
+const FLASH_DURATION: Duration = Duration::from_millis(200);
+
+/// How long a row, column, or box flashes green after being completed
+/// correctly.
+const UNIT_FLASH_DURATION: Duration = Duration::from_millis(600);
+
+/// How long a status message set via `App::set_status` without an explicit
+/// TTL stays on screen before fading.
+const DEFAULT_STATUS_TTL: Duration = Duration::from_secs(3);
+
+/// How often the display redraws on its own, independent of player input,
+/// so the timer keeps moving without polling (and redrawing) far more often
+/// than the screen can actually change.
+const TICK_RATE: Duration = Duration::from_millis(250);
+
+/// How many wrong entries a lives-mode game allows before it's over.
+const STARTING_LIVES: u32 = 3;
+
+/// The largest single gap trusted between two clock readings while the
+/// timer is running. A system clock jump or a long suspend can make
+/// `Instant::duration_since` report an enormous span instead of the real
+/// one; capping it here keeps a fluke from inflating the recorded time
+/// into nonsense.
+const MAX_ELAPSED_DELTA: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// The event poll timeout right after input, before any idling has had a
+/// chance to grow it. Kept short so the very next key press still feels
+/// immediate.
+const MIN_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long a completely untouched game is allowed to let the poll timeout
+/// grow to, capping how far `App::poll_timeout` can back off.
+const DEFAULT_MAX_IDLE_POLL: Duration = Duration::from_millis(250);
+
+/// How long the menu has to sit untouched before attract mode kicks in.
+const ATTRACT_IDLE_DELAY: Duration = Duration::from_secs(30);
+
+/// How often attract mode fills in another cell, once running.
+const ATTRACT_MOVE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How often the cursor's blink toggles on or off.
+const CURSOR_BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Which screen the app is currently showing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Screen {
+    /// The difficulty-selection screen shown at launch.
+    #[default]
+    Menu,
+    /// A puzzle is in progress.
+    Playing,
+    /// The board has been completed.
+    Won,
+    /// A countdown-mode game reached zero before the board was solved.
+    TimesUp,
+    /// A lives-mode game ran out of lives before the board was solved.
+    GameOver,
+    /// The per-difficulty completion stats screen, opened from the menu.
+    Stats,
+    /// Stepping back through a won game's move history, opened from the
+    /// win screen.
+    Replay,
+    /// Browsing named save slots, opened from the menu.
+    Load,
+    /// Browsing the bundled puzzle library, opened from the menu.
+    Library,
+    /// Building a custom puzzle by hand, opened from the menu. Digits place
+    /// `Given` cells rather than normal entries; `Enter` locks the puzzle
+    /// in and starts playing it once it has a unique solution.
+    Authoring,
+    /// A post-game dwell-time heatmap, opened from the win screen.
+    Heatmap,
+    /// The in-app settings screen, opened from the menu.
+    Settings,
+}
+
+/// A teaching hint `App::next_hint` has identified but not yet carried out,
+/// shown to the player so they can learn the technique before it's applied.
+#[derive(Debug, Clone)]
+struct PendingHint {
+    /// The technique's name, as shown to the player (e.g. "hidden single").
+    technique: &'static str,
+    /// A plain-language explanation of why `action` follows from the board.
+    explanation: String,
+    /// The cell(s) the explanation refers to, highlighted on the board.
+    cells: std::collections::HashSet<(usize, usize)>,
+    /// What a second hint press carries out.
+    action: HintAction,
+}
+
+/// What a pending hint does once the player applies it.
+#[derive(Debug, Clone, Copy)]
+enum HintAction {
+    /// Fills an empty cell with its correct digit.
+    Fill { row: usize, col: usize, digit: u8 },
+    /// Removes a digit from a cell's pencil marks.
+    EliminateNote { row: usize, col: usize, digit: u8 },
+}
+
 #[derive(Debug, Default)]
 pub struct App {
     exit: bool,
+    screen: Screen,
+    menu_selection: usize,
+    /// Which of `VARIANTS` is highlighted on the menu screen.
+    size_selection: usize,
     is_timer_running: bool,
     start_time: Option<Instant>,
     elapsed_time: Duration,
+    /// The board, selected cell, move/mistake counters, and solution,
+    /// isolated from every timer/input/rendering concern here so it's
+    /// testable on its own. Cursor movement and notes don't count toward
+    /// its move counter.
+    game: Game,
+    notes_mode: bool,
+    undo_stack: UndoStack,
+    hints_used: u32,
+    /// A teaching hint that's been shown but not yet applied, waiting on a
+    /// second `Action::Hint` press. Dropped by any other key.
+    pending_hint: Option<PendingHint>,
+    /// Whether the full solution is currently overlaid faintly over every
+    /// empty cell, toggled by `Action::PeekSolution`.
+    peek_solution: bool,
+    /// How many times `peek_solution` has been switched on this game,
+    /// counted toward the score the same as a hint.
+    peeks_used: u32,
+    difficulty: Difficulty,
+    /// How hard the current puzzle actually is, per `rating::rate`, as
+    /// opposed to `difficulty`, which is only the generator's request.
+    puzzle_rating: Difficulty,
+    best_times: stats::BestTimes,
+    is_new_record: bool,
+    /// The most recently won game's score, from `score::compute`, shown on
+    /// the win screen.
+    current_score: u32,
+    /// The highest score recorded for each difficulty.
+    high_scores: stats::HighScores,
+    is_new_high_score: bool,
+    /// Per-difficulty games-completed and total-time counters, for the
+    /// stats screen.
+    completion_stats: stats::Stats,
+    /// The grid's bounding rect from the most recent render, used to map
+    /// mouse clicks to a `(row, col)` in `cell_at`.
+    table_area: Rect,
+    /// Whether the selected cell's row, column, and box are dimmed to make
+    /// them easier to scan.
+    highlight_peers: bool,
+    /// Whether every empty cell with exactly one legal candidate is
+    /// highlighted, nudging the player toward naked singles without filling
+    /// them in. Recomputed on every render, so it stays live as the board
+    /// changes.
+    coaching_mode: bool,
+    /// Whether the header's numeric timer is replaced with `--:--`, for
+    /// players who find a visibly ticking clock stressful. Elapsed time is
+    /// still tracked as normal underneath and revealed on the win screen.
+    hide_timer: bool,
+    /// Whether the timer is currently paused because the terminal lost
+    /// focus, as opposed to a manual `p`. Only that pause should be
+    /// resumed automatically when focus returns.
+    focus_paused: bool,
+    /// Whether the keybindings help overlay is currently shown.
+    show_help: bool,
+    /// Whether the timer is currently paused because the help overlay
+    /// opened it, as opposed to a manual `p`. Only that pause should be
+    /// resumed automatically when the overlay closes.
+    help_paused: bool,
+    /// Whether the "erase all my entries" confirmation prompt is currently
+    /// shown, waiting for a yes/no answer.
+    confirm_reset: bool,
+    /// Whether the "quit without saving" confirmation prompt is currently
+    /// shown, waiting for a yes/no answer.
+    confirm_quit: bool,
+    /// Whether the "restart from scratch" confirmation prompt is currently
+    /// shown, waiting for a yes/no answer.
+    confirm_restart: bool,
+    /// Whether the next game started from the menu counts down from
+    /// `DEFAULT_TIME_LIMIT` instead of counting up. Toggled on the menu.
+    countdown_mode: bool,
+    /// The countdown limit for the current game, or `None` in the normal
+    /// count-up mode. Set from `countdown_mode` when a game starts.
+    time_limit: Option<Duration>,
+    /// The cell to flash red, and until when, after a rejected attempt to
+    /// overwrite a given or, in strict mode, a wrong digit. `None` once the
+    /// flash has expired or never started.
+    flash: Option<((usize, usize), Instant)>,
+    /// The cells to flash green, and until when, after completing a row,
+    /// column, or box correctly. `None` once the flash has expired or never
+    /// started.
+    unit_flash: Option<(Vec<(usize, usize)>, Instant)>,
+    /// A transient message (e.g. "Saved", "No hints left") and when it
+    /// expires, shown below the grid until then. `None` once expired or
+    /// never set.
+    status: Option<(String, Instant)>,
+    /// Whether the next game started from the menu rejects wrong entries
+    /// outright instead of letting them sit on the board. Toggled on the
+    /// menu.
+    strict_mode: bool,
+    /// Whether the next game started from the menu is today's daily
+    /// challenge (same board for everyone, at the classic 9x9 size) rather
+    /// than a freshly shuffled puzzle. Toggled on the menu.
+    daily_mode: bool,
+    /// Whether the next game started from the menu limits the player to
+    /// `STARTING_LIVES` wrong entries before it's over. Toggled on the menu.
+    lives_mode: bool,
+    /// How many wrong entries the current lives-mode game can still take
+    /// before it's over. Meaningless when `lives_mode` is off.
+    lives_remaining: u32,
+    /// A vim-style count prefix (e.g. the `3` in `3j`) armed by the most
+    /// recent digit key, multiplying the next cursor movement. Consumed on
+    /// the next move, or dropped on any other key.
+    pending_count: Option<u32>,
+    /// Whether the next digit key jumps the cursor to that box's top-left
+    /// cell (numbered like a phone keypad) instead of filling the selected
+    /// cell. Armed by `Action::GoToBox`, consumed by the next digit.
+    go_to_box_mode: bool,
+    /// Whether the next digit key sets (or, pressed again, clears)
+    /// `digit_focus` instead of filling the selected cell. Armed by
+    /// `Action::DigitFocus`, consumed by the next digit.
+    digit_focus_mode: bool,
+    /// The digit currently focused for scanning, if any: every cell that
+    /// neither holds nor could legally hold it is dimmed, so a player can
+    /// place every instance of one value in turn.
+    digit_focus: Option<u8>,
+    /// Whether the next digit key tags (or, for `0`, clears) the selected
+    /// cell's annotation color instead of filling it. Armed by
+    /// `Action::Annotate`, consumed by the next digit.
+    annotate_mode: bool,
+    /// The named save slots found under `persistence::default_slots_dir`,
+    /// refreshed each time the load screen is opened from the menu.
+    slots: Vec<persistence::SlotInfo>,
+    /// Which entry of `slots` is highlighted on the load screen.
+    load_selection: usize,
+    /// The bundled puzzle library, loaded once when the library screen is
+    /// first opened rather than on every `App::default()`.
+    library: Vec<library::NamedPuzzle>,
+    /// Which entry of `library` is highlighted on the library screen.
+    library_selection: usize,
+    /// Whether the next game started from the menu is an X-Sudoku variant,
+    /// where the two main diagonals must also hold every digit exactly
+    /// once. Toggled on the menu.
+    diagonal_mode: bool,
+    /// Whether placing or clearing a digit also keeps peers' pencil marks
+    /// in sync, removing the digit from a peer's notes when it's placed
+    /// and restoring it when it's cleared and legal again. Off by default
+    /// so manual note-takers aren't disrupted. Toggled on the menu.
+    auto_notes: bool,
+    /// A snapshot of the board as it looked when the current game started,
+    /// before any moves, for the post-game replay screen to replay moves
+    /// onto.
+    start_board: Option<Board>,
+    /// Every move made this game, timestamped by how long into the game it
+    /// happened, for the post-game replay screen.
+    move_history: Vec<(Duration, Move)>,
+    /// How many of `move_history`'s moves are currently applied on the
+    /// replay screen; `0` is the puzzle's starting position.
+    replay_step: usize,
+    /// The clock timer calculations read from. Swapped for a `MockClock`
+    /// in tests so timing behavior is deterministic.
+    clock: Box<dyn Clock>,
+    /// Restores the terminal on a failed draw. Swapped for a mock in tests
+    /// so the recovery path doesn't issue real terminal escape codes.
+    terminal_restore: Box<dyn Restore>,
+    /// How often `run`'s loop redraws on its own, rather than in response to
+    /// input. `Duration::ZERO` on a bare `App::default()`; real games get
+    /// `TICK_RATE` from `with_saved_preferences`.
+    tick_rate: Duration,
+    /// When `run`'s loop last redrew for a tick rather than an input event.
+    /// `None` before the first tick.
+    last_tick: Option<Instant>,
+    /// When the player last went idle (i.e. a poll came back with no
+    /// event), so `poll_timeout` knows how long that's been. `None` right
+    /// after an event, so idling always starts measuring from zero.
+    idle_since: Option<Instant>,
+    /// How far `poll_timeout` is allowed to back off while idle.
+    /// `Duration::ZERO` on a bare `App::default()`; real games get
+    /// `DEFAULT_MAX_IDLE_POLL` from `with_saved_preferences`.
+    max_idle_poll: Duration,
+    /// How long the cursor has dwelled on each cell this game, accumulated
+    /// one tick's worth at a time by `record_dwell` while `Screen::Playing`
+    /// is active. Sized for the largest board (`Classic9`); smaller boards
+    /// simply leave the extra rows and columns at zero. Read by the
+    /// post-game heatmap opened from the win screen.
+    cell_dwell: [[Duration; 9]; 9],
+    /// How long the menu screen has sat untouched, so attract mode knows
+    /// when to kick in. Reset to `None` by any keypress or by leaving the
+    /// menu; only advanced by `advance_attract_mode`.
+    attract_idle_since: Option<Instant>,
+    /// Whether attract mode is auto-playing a demo puzzle. Any keypress
+    /// exits it back to the menu; while it's active, `advance_attract_mode`
+    /// fills in one correct cell every `ATTRACT_MOVE_INTERVAL` until the
+    /// puzzle is solved, then starts a fresh one and keeps going.
+    attract_mode: bool,
+    /// When attract mode last filled in a cell, so it moves at
+    /// `ATTRACT_MOVE_INTERVAL` regardless of the render tick rate.
+    last_attract_move: Option<Instant>,
+    /// The keys bound to each in-game action, loaded from the user's
+    /// config file at startup.
+    key_bindings: KeyBindings,
+    /// The active color theme, loaded from the user's config file at
+    /// startup.
+    theme: Theme,
+    /// Which named theme `theme` was resolved from, kept alongside it so
+    /// the settings screen can cycle and re-save it without reversing
+    /// `Theme::named`.
+    theme_name: ThemeName,
+    /// Where `theme_name` is written when it changes in the settings
+    /// screen. Set to the real config path by `with_saved_preferences`;
+    /// left empty on a bare `App::default()` so tests can point it at a
+    /// temp file.
+    theme_path: PathBuf,
+    /// Small on/off preferences (currently just the terminal bell), loaded
+    /// from the user's config file at startup.
+    settings: Settings,
+    /// Where `settings` is written when it changes in the settings screen.
+    /// Set to the real config path by `with_saved_preferences`; left empty
+    /// on a bare `App::default()` so tests can point it at a temp file.
+    settings_path: PathBuf,
+    /// Which settings-screen row is highlighted.
+    settings_selection: usize,
+    /// Where audible cues (currently just the terminal bell) go. Swapped
+    /// for a mock in tests so they don't actually ring.
+    feedback: Box<dyn Feedback>,
+    /// Where a pasted puzzle string is read from when importing. Swapped
+    /// for a mock in tests so imports don't touch the real system
+    /// clipboard.
+    clipboard: Box<dyn Clipboard>,
 }
 
 impl App {
-    /// runs the application's main loop until the user quits
-    pub fn run(&mut self, terminal: &mut tui::Tui) -> io::Result<()> {
+    /// Restores a saved game from the default save path, if one exists.
+    /// A restored game goes straight to the (paused) board rather than the
+    /// menu, since there's no empty grid to hide behind a difficulty pick.
+    fn load_or_default() -> Self {
+        let mut app = Self::with_saved_preferences();
+        if let Ok((board, elapsed)) = persistence::load(&persistence::default_save_path()) {
+            *app.game.board_mut() = board;
+            app.elapsed_time = elapsed;
+            app.screen = Screen::Playing;
+        }
+        app
+    }
+
+    /// Starts directly on the playing screen with `board`'s givens, for
+    /// `rusuku path/to/puzzle.txt`, skipping the menu entirely. The
+    /// solution is solved up front the same way `start_game` does, so
+    /// strict mode works on a puzzle loaded this way too.
+    fn from_puzzle(board: Board) -> Self {
+        let mut app = Self::with_saved_preferences();
+        app.puzzle_rating = rating::rate(&board);
+        app.game.set_solution(solver::solve(&board));
+        app.start_board = Some(board.clone());
+        *app.game.board_mut() = board;
+        app.lives_remaining = STARTING_LIVES;
+        app.screen = Screen::Playing;
+        app.start_timer();
+        app
+    }
+
+    /// Builds a fresh `App` with the player's saved key bindings, theme, and
+    /// best times loaded, but no game in progress.
+    fn with_saved_preferences() -> Self {
+        let theme_name = theme::load_name(&theme::default_path());
+        let settings = Settings::load(&config::default_settings_path());
+        let menu_selection = DIFFICULTIES.iter().position(|&d| d == settings.default_difficulty).unwrap_or(0);
+        App {
+            best_times: stats::load(&stats::default_path()),
+            high_scores: stats::load_high_scores(&stats::default_high_scores_path()),
+            completion_stats: stats::load_stats(&stats::default_stats_path()),
+            key_bindings: KeyBindings::load(&config::default_path()),
+            theme: theme::load(&theme::default_path()),
+            theme_name,
+            theme_path: theme::default_path(),
+            settings,
+            settings_path: config::default_settings_path(),
+            menu_selection,
+            tick_rate: TICK_RATE,
+            max_idle_poll: DEFAULT_MAX_IDLE_POLL,
+            ..App::default()
+        }
+    }
+
+    /// runs the application's main loop until the user quits. Redraws
+    /// immediately whenever input is handled, and otherwise at most once per
+    /// `tick_rate`, so an idle game doesn't spin the CPU polling and
+    /// redrawing far faster than anything on screen (namely the timer)
+    /// actually changes.
+    pub fn run<B: ratatui::backend::Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
         loop {
             if self.exit {
                 break;
             }
-            terminal.draw(|frame| {
-                let area = frame.area();
+            self.check_time_up();
 
-                // self.render_frame(frame);
-                let layout = Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints([Constraint::Percentage(15), Constraint::Percentage(85)])
-                    .split(area);
+            let elapsed_since_tick = self.last_tick.map_or(self.tick_rate, |last| self.clock.now().duration_since(last));
+            let timeout = self.tick_rate.saturating_sub(elapsed_since_tick).min(self.poll_timeout());
+            let redraw_for_input = self.handle_events(timeout)?;
+            let redraw_for_tick = self.tick_elapsed();
+            if redraw_for_tick {
+                self.record_dwell(self.tick_rate);
+                self.advance_attract_mode();
+            }
 
-                render_header(frame, self, layout[0]);
-                render_table(frame, self, layout[1]);
-            })?;
+            if redraw_for_input {
+                self.idle_since = None;
+            } else if self.idle_since.is_none() {
+                self.idle_since = Some(self.clock.now());
+            }
 
-            self.handle_events()?;
+            if redraw_for_input || redraw_for_tick {
+                self.draw(terminal)?;
+            }
         }
         Ok(())
     }
 
-    fn handle_events(&mut self) -> io::Result<()> {
-        if event::poll(std::time::Duration::from_millis(10))? {
-            match event::read()? {
-                // it's important to check that the event is a key press event as
-                // crossterm also emits key release and repeat events on Windows.
-                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                    self.handle_key_event(key_event)
-                }
-                _ => {}
-            };
+    /// Whether at least `tick_rate` has passed since the last tick. If so,
+    /// records `now` as the new last tick so the next call measures from
+    /// here. Drives the fixed-rate redraw that keeps the timer moving even
+    /// without player input.
+    fn tick_elapsed(&mut self) -> bool {
+        let now = self.clock.now();
+        let elapsed = self.last_tick.is_none_or(|last| now.duration_since(last) >= self.tick_rate);
+        if elapsed {
+            self.last_tick = Some(now);
         }
-        Ok(())
+        elapsed
     }
 
-    fn handle_key_event(&mut self, key_event: KeyEvent) {
-        match key_event.code {
-            KeyCode::Char('q') => self.exit(),
-            KeyCode::Char('i') => self.start_game(),
-            KeyCode::Char('p') => self.stop_timer(),
-            KeyCode::Char('c') => self.continue_timer(),
-            _ => {}
+    /// Adds `elapsed` to the currently selected cell's dwell time, for the
+    /// post-game heatmap. Only accumulates during actual play, so time
+    /// spent sitting on the menu or a paused game doesn't count.
+    fn record_dwell(&mut self, elapsed: Duration) {
+        if self.screen != Screen::Playing {
+            return;
         }
+        let (row, col) = self.game.cursor();
+        self.cell_dwell[row][col] += elapsed;
     }
 
-    fn start_game(&mut self) {
-        self.start_timer();
-    }
-
-    fn start_timer(&mut self) {
-        if self.is_timer_running {
+    /// Drives attract mode: starts it once the menu has sat idle for
+    /// `ATTRACT_IDLE_DELAY`, and while it's running, fills in one
+    /// solver-correct cell every `ATTRACT_MOVE_INTERVAL` until the puzzle
+    /// is solved, then starts a fresh one so the demo loops indefinitely.
+    fn advance_attract_mode(&mut self) {
+        if self.attract_mode {
+            let due = self.last_attract_move.is_none_or(|last| self.clock.now().duration_since(last) >= ATTRACT_MOVE_INTERVAL);
+            if due {
+                self.play_one_attract_move();
+            }
             return;
         }
-        self.is_timer_running = true;
-        self.start_time = Some(Instant::now());
-    }
-
-    fn stop_timer(&mut self) {
-        if !self.is_timer_running {
+        if self.screen != Screen::Menu {
+            self.attract_idle_since = None;
             return;
         }
-        self.is_timer_running = false;
-
-        if let Some(start_time) = self.start_time {
-            self.elapsed_time += start_time.elapsed();
-            self.start_time = None;
+        if self.attract_idle_since.is_none() {
+            self.attract_idle_since = Some(self.clock.now());
+        }
+        let idle_since = self.attract_idle_since.unwrap();
+        if self.clock.now().duration_since(idle_since) >= ATTRACT_IDLE_DELAY {
+            self.start_attract_mode();
         }
     }
 
-    fn continue_timer(&mut self) {
-        if self.is_timer_running {
+    /// Starts (or restarts) the demo puzzle attract mode auto-plays.
+    fn start_attract_mode(&mut self) {
+        self.start_game(DIFFICULTIES[self.menu_selection], VARIANTS[self.size_selection]);
+        self.attract_mode = true;
+        self.attract_idle_since = None;
+        self.last_attract_move = None;
+    }
+
+    /// Fills in one empty cell with its solution digit, and starts a fresh
+    /// puzzle once the board is completely solved.
+    fn play_one_attract_move(&mut self) {
+        self.last_attract_move = Some(self.clock.now());
+        let Some(solution) = self.game.solution().cloned() else {
             return;
+        };
+        let side = self.game.board().size().side();
+        let next_empty = (0..side)
+            .flat_map(|row| (0..side).map(move |col| (row, col)))
+            .find(|&(row, col)| self.game.board().get(row, col) == Cell::Empty);
+        if let Some((row, col)) = next_empty {
+            let digit = solution.get(row, col).digit().expect("solution cells are always filled");
+            self.game.board_mut().set(row, col, Cell::Filled(digit));
+        }
+        if self.game.is_won() {
+            self.start_attract_mode();
         }
-        self.start_time = Some(Instant::now());
-        self.is_timer_running = true;
     }
 
-    fn elapsed(&self) -> Duration {
-        if let Some(start_time) = self.start_time {
-            if self.is_timer_running {
-                return self.elapsed_time + start_time.elapsed();
-            }
-        }
-        self.elapsed_time
+    /// Exits attract mode back to the menu, discarding its demo game.
+    fn stop_attract_mode(&mut self) {
+        self.attract_mode = false;
+        self.attract_idle_since = None;
+        self.last_attract_move = None;
+        self.stop_timer();
+        self.screen = Screen::Menu;
     }
 
-    fn exit(&mut self) {
-        self.exit = true;
+    /// How long the next event poll should wait. Starts at
+    /// `MIN_POLL_INTERVAL` right after input and grows with how long the
+    /// player's been idle, capped at `max_idle_poll`, so an untouched game
+    /// doesn't poll (and wake the CPU) as eagerly as one mid-play.
+    fn poll_timeout(&self) -> Duration {
+        let idle_for = self.idle_since.map_or(Duration::ZERO, |since| self.clock.now().duration_since(since));
+        (MIN_POLL_INTERVAL + idle_for).min(self.max_idle_poll.max(MIN_POLL_INTERVAL))
     }
-}
-fn render_header(f: &mut Frame, app: &mut App, area: Rect) {
-    let title = Title::from(" Welcome to Rusuku ".bold());
 
-    let header_layout = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Fill(1); 3])
-        .split(area);
+    /// Draws one frame. A draw error is rare (it means the terminal itself
+    /// is misbehaving) but fatal, so rather than leave the terminal stuck in
+    /// raw/alternate-screen mode for the player to clean up by hand, this
+    /// restores it first, then surfaces the error to `run`'s caller.
+    fn draw<B: ratatui::backend::Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        terminal.draw(|frame| render_frame(frame, self)).inspect_err(|err| {
+            eprintln!("rusuku: failed to draw the terminal ({err}), restoring and exiting");
+            let _ = self.terminal_restore.restore();
+        })?;
+        Ok(())
+    }
 
-    let minutes = app.elapsed().as_secs() / 60;
-    let seconds = app.elapsed().as_secs() % 60;
-    let elapsed_time = format!("{:02}:{:02}", minutes, seconds);
-    let elapsed_time = Text::from(elapsed_time.to_string().yellow().bold());
+    /// Polls for at most `timeout` and handles at most one event, returning
+    /// whether anything was handled (and so the screen may need a redraw).
+    fn handle_events(&mut self, timeout: Duration) -> io::Result<bool> {
+        if !event::poll(timeout)? {
+            return Ok(false);
+        }
 
-    let top_middle = Block::bordered()
-        .title(title.alignment(Alignment::Center))
-        .borders(Borders::TOP | Borders::BOTTOM)
-        .border_set(border::THICK);
+        match event::read()? {
+            // it's important to check that the event is a key press event as
+            // crossterm also emits key release and repeat events on Windows.
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                self.handle_key_event(key_event);
+            }
+            Event::Mouse(mouse_event) if mouse_event.kind == MouseEventKind::Down(MouseButton::Left) => {
+                self.handle_click(mouse_event.column, mouse_event.row);
+            }
+            Event::FocusLost => self.pause_for_focus_loss(),
+            Event::FocusGained => self.resume_from_focus_gain(),
+            _ => {}
+        };
+        Ok(true)
+    }
 
-    f.render_widget(
-        Paragraph::new(elapsed_time).centered().block(top_middle),
-        header_layout[1],
-    );
+    /// Moves the cursor to the cell under a left click, ignoring clicks
+    /// outside the grid or before the game has started.
+    fn handle_click(&mut self, column: u16, row: u16) {
+        if !self.is_timer_running {
+            return;
+        }
+        if let Some(cell) = self.cell_at(column, row) {
+            self.game.set_cursor(cell);
+        }
+    }
 
-    f.render_widget(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_set(border::THICK),
-        header_layout[0],
-    );
+    /// Maps a terminal coordinate to the `(row, col)` of the grid cell it
+    /// falls in, or `None` if it's outside `table_area`. Mirrors the nested
+    /// box/cell layout that `render_table` builds: a grid of bordered boxes
+    /// sized to the board, each one cell wide/tall per border.
+    fn cell_at(&self, column: u16, row: u16) -> Option<(usize, usize)> {
+        if !self.table_area.contains((column, row).into()) {
+            return None;
+        }
 
-    f.render_widget(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_set(border::THICK),
-        header_layout[2],
-    );
-}
+        let (box_rows, box_cols) = self.game.board().size().box_dims();
+        let side = self.game.board().size().side();
+        let n_cols = side / box_cols;
+        let n_rows = side / box_rows;
 
-fn render_table(f: &mut Frame, _: &mut App, area: Rect) {
-    let vertical_layout = Layout::default()
-        .constraints([Constraint::Max(18); 3])
-        .direction(Direction::Horizontal)
-        .flex(Flex::Center)
-        .split(area);
+        let vertical_layout = Layout::default()
+            .constraints(vec![Constraint::Max(box_width(box_cols)); n_cols])
+            .direction(Direction::Horizontal)
+            .flex(Flex::Center)
+            .split(self.table_area);
+
+        let vi = vertical_layout.iter().position(|vl| vl.left() <= column && column < vl.right())?;
 
-    for (vi, vl) in vertical_layout.iter().enumerate() {
         let horizontal_layout = Layout::default()
-            .constraints([Constraint::Max(18); 3])
+            .constraints(vec![Constraint::Max(box_height(box_rows)); n_rows])
             .direction(Direction::Vertical)
-            .split(*vl);
-
-        for (hi, hl) in horizontal_layout.iter().enumerate() {
-            let border_set = match (vi, hi) {
-                (0, 0) => symbols::border::Set {
-                    bottom_left: symbols::line::THICK_VERTICAL_RIGHT,
-                    ..symbols::border::THICK
-                },
-                (1, 0) => symbols::border::Set {
-                    top_right: symbols::line::THICK_HORIZONTAL_DOWN,
-                    top_left: symbols::line::THICK_HORIZONTAL_DOWN,
-                    bottom_left: symbols::line::THICK_CROSS,
-                    bottom_right: symbols::line::THICK_CROSS,
-                    ..symbols::border::THICK
-                },
-                (2, 0) => symbols::border::Set {
-                    bottom_right: symbols::line::THICK_VERTICAL_LEFT,
-                    ..symbols::border::THICK
-                },
-                (0, 1) => symbols::border::Set {
-                    bottom_left: symbols::line::THICK_VERTICAL_RIGHT,
-                    ..symbols::border::THICK
-                },
-                (1, 1) => symbols::border::Set {
-                    bottom_left: symbols::line::THICK_CROSS,
-                    bottom_right: symbols::line::THICK_CROSS,
-                    ..symbols::border::THICK
-                },
-                (2, 1) => symbols::border::Set {
-                    bottom_right: symbols::line::THICK_VERTICAL_LEFT,
-                    ..symbols::border::THICK
-                },
-                (0, 2) => symbols::border::THICK,
-                (1, 2) => symbols::border::Set {
-                    bottom_left: symbols::line::THICK_HORIZONTAL_UP,
-                    bottom_right: symbols::line::THICK_HORIZONTAL_UP,
-                    ..symbols::border::THICK
-                },
-                (2, 2) => symbols::border::THICK,
-                _ => symbols::border::THICK,
-            };
+            .flex(Flex::Center)
+            .split(vertical_layout[vi]);
 
-            let borders = match (vi, hi) {
-                (0, 0) => Borders::LEFT | Borders::TOP | Borders::BOTTOM,
-                (1, 0) => Borders::ALL,
-                (2, 0) => Borders::TOP | Borders::RIGHT | Borders::BOTTOM,
-                (0, 1) => Borders::LEFT | Borders::BOTTOM,
-                (1, 1) => Borders::RIGHT | Borders::LEFT | Borders::BOTTOM,
-                (2, 1) => Borders::BOTTOM | Borders::RIGHT,
-                (0, 2) => Borders::LEFT | Borders::BOTTOM,
-                (1, 2) => Borders::LEFT | Borders::BOTTOM | Borders::RIGHT,
-                (2, 2) => Borders::BOTTOM | Borders::RIGHT,
-                _ => Borders::ALL,
-            };
+        let hi = horizontal_layout.iter().position(|hl| hl.top() <= row && row < hl.bottom())?;
 
-            Block::default()
-                .borders(borders)
-                .border_set(border_set)
-                .render(*hl, f.buffer_mut());
+        let block = Block::default().borders(box_borders(vi, hi));
+        let inner = block.inner(horizontal_layout[hi]);
+        if !inner.contains((column, row).into()) {
+            return None;
         }
-    }
-}
 
-impl Widget for &App {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let title = Title::from(" Welcome to Rusuku ".bold());
-        let layout = Layout::default()
+        let cell_rows = Layout::default()
+            .constraints(vec![Constraint::Ratio(1, box_rows as u32); box_rows])
             .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(15), Constraint::Percentage(85)])
-            .split(area);
+            .split(inner);
+        let r = cell_rows.iter().position(|ra| ra.top() <= row && row < ra.bottom())?;
 
-        let header_layout = Layout::default()
+        let cell_cols = Layout::default()
+            .constraints(vec![Constraint::Ratio(1, box_cols as u32); box_cols])
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Fill(1); 3])
-            .split(layout[0]);
+            .split(cell_rows[r]);
+        let c = cell_cols.iter().position(|ca| ca.left() <= column && column < ca.right())?;
 
-        let minutes = self.elapsed().as_secs() / 60;
-        let seconds = self.elapsed().as_secs() % 60;
-        let elapsed_time = format!("{:02}:{:02}", minutes, seconds);
-        let elapsed_time = Text::from(elapsed_time.to_string().yellow().bold());
+        Some((hi * box_rows + r, vi * box_cols + c))
+    }
 
-        let top_middle = Block::bordered()
-            .title(title.alignment(Alignment::Center))
-            .borders(Borders::TOP | Borders::BOTTOM)
-            .border_set(border::THICK);
+    /// Dispatches a key press to the current screen's handler, except while
+    /// a confirmation prompt or the help overlay is open: a confirmation
+    /// only recognizes `y`/Enter (confirm) versus anything else (cancel),
+    /// and the help overlay only recognizes `?` and `Esc` (to close it),
+    /// swallowing everything else.
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if self.attract_mode {
+            return self.stop_attract_mode();
+        }
+        if key_event.code == KeyCode::Char('c') && key_event.modifiers.contains(KeyModifiers::CONTROL) {
+            self.handle_interrupt();
+            return;
+        }
+        if self.confirm_reset {
+            if matches!(key_event.code, KeyCode::Char('y') | KeyCode::Enter) {
+                self.reset_to_givens();
+            }
+            self.confirm_reset = false;
+            return;
+        }
+        if self.confirm_quit {
+            if matches!(key_event.code, KeyCode::Char('y') | KeyCode::Enter) {
+                self.exit();
+            }
+            self.confirm_quit = false;
+            return;
+        }
+        if self.confirm_restart {
+            if matches!(key_event.code, KeyCode::Char('y') | KeyCode::Enter) {
+                self.restart();
+            }
+            self.confirm_restart = false;
+            return;
+        }
+        if self.show_help {
+            if matches!(key_event.code, KeyCode::Char('?') | KeyCode::Esc) {
+                self.toggle_help();
+            }
+            return;
+        }
+        if key_event.code == KeyCode::Char('?')
+            && !matches!(
+                self.screen,
+                Screen::Menu | Screen::Stats | Screen::Replay | Screen::Load | Screen::Library | Screen::Settings
+            )
+        {
+            self.toggle_help();
+            return;
+        }
 
-        Paragraph::new(elapsed_time)
-            .centered()
-            .block(top_middle)
-            .render(header_layout[1], buf);
+        match self.screen {
+            Screen::Menu => self.handle_menu_key(key_event),
+            Screen::Playing | Screen::Won | Screen::TimesUp | Screen::GameOver => self.handle_game_key(key_event),
+            Screen::Stats => self.handle_stats_key(key_event),
+            Screen::Replay => self.handle_replay_key(key_event),
+            Screen::Load => self.handle_load_key(key_event),
+            Screen::Library => self.handle_library_key(key_event),
+            Screen::Authoring => self.handle_authoring_key(key_event),
+            Screen::Heatmap => self.handle_heatmap_key(key_event),
+            Screen::Settings => self.handle_settings_key(key_event),
+        }
+    }
 
-        Block::default()
-            .borders(Borders::ALL)
-            .border_set(border::THICK)
-            .render(header_layout[0], buf);
+    /// Toggles the keybindings help overlay. Whether this pauses the timer
+    /// is controlled by `PAUSE_TIMER_ON_HELP`; if it does, closing the
+    /// overlay resumes the timer only if the overlay itself paused it.
+    fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
+        if !PAUSE_TIMER_ON_HELP {
+            return;
+        }
+        if self.show_help {
+            if self.is_timer_running {
+                self.stop_timer();
+                self.help_paused = true;
+            }
+        } else if self.help_paused {
+            self.help_paused = false;
+            self.continue_timer();
+        }
+    }
 
-        Block::default()
-            .borders(Borders::ALL)
-            .border_set(border::THICK)
-            .render(header_layout[2], buf);
+    /// Handles input on the difficulty-selection menu: up/down move the
+    /// difficulty selection, left/right move the board size selection, `t`
+    /// toggles countdown mode, `w` toggles strict mode, `d` toggles today's
+    /// daily challenge, `m` toggles lives mode, `x` toggles the X-Sudoku
+    /// diagonal variant, `i` opens the stats screen, `L` opens the load
+    /// screen, `a` opens the puzzle-authoring screen, `O` opens the settings
+    /// screen, Enter generates a puzzle at that difficulty and size and
+    /// starts it.
+    fn handle_menu_key(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Char('q') => self.exit(),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.menu_selection = self.menu_selection.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.menu_selection = (self.menu_selection + 1).min(DIFFICULTIES.len() - 1);
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.size_selection = self.size_selection.saturating_sub(1);
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.size_selection = (self.size_selection + 1).min(VARIANTS.len() - 1);
+            }
+            KeyCode::Char('t') => self.countdown_mode = !self.countdown_mode,
+            KeyCode::Char('w') => self.strict_mode = !self.strict_mode,
+            KeyCode::Char('d') => self.daily_mode = !self.daily_mode,
+            KeyCode::Char('m') => self.lives_mode = !self.lives_mode,
+            KeyCode::Char('x') => self.diagonal_mode = !self.diagonal_mode,
+            KeyCode::Char('A') => self.auto_notes = !self.auto_notes,
+            KeyCode::Char('E') => self.settings.expert_rendering = !self.settings.expert_rendering,
+            KeyCode::Char('i') => self.screen = Screen::Stats,
+            KeyCode::Char('L') => self.open_load_screen(),
+            KeyCode::Char('b') => self.open_library_screen(),
+            KeyCode::Char('a') => self.open_authoring_screen(),
+            KeyCode::Char('O') => self.screen = Screen::Settings,
+            KeyCode::Enter => {
+                self.start_game(DIFFICULTIES[self.menu_selection], VARIANTS[self.size_selection])
+            }
+            _ => {}
+        }
+    }
 
-        Block::bordered()
-            .border_set(border::THICK)
-            .render(layout[1], buf);
+    /// Starts a fresh, empty board of the selected size for the player to
+    /// fill in with givens by hand.
+    fn open_authoring_screen(&mut self) {
+        *self.game.board_mut() = Board::new(VARIANTS[self.size_selection]);
+        self.game.set_cursor((0, 0));
+        self.screen = Screen::Authoring;
     }
-}
 
-#[cfg(test)]
-mod tests {
+    /// Handles input on the puzzle-authoring screen: the arrow keys (or
+    /// vim-style `hjkl`) move the cursor, digit keys place (or, `0`, clear)
+    /// a `Given` cell there instead of a normal entry, Enter locks the
+    /// puzzle in and starts playing it if it has exactly one solution, and
+    /// Esc abandons it and returns to the menu.
+    fn handle_authoring_key(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') => self.game.move_cursor(-1, 0),
+            KeyCode::Down | KeyCode::Char('j') => self.game.move_cursor(1, 0),
+            KeyCode::Left | KeyCode::Char('h') => self.game.move_cursor(0, -1),
+            KeyCode::Right | KeyCode::Char('l') => self.game.move_cursor(0, 1),
+            KeyCode::Char(c) if char_to_digit(c, self.game.board().size()).is_some() => {
+                self.author_cell(char_to_digit(c, self.game.board().size()).unwrap())
+            }
+            KeyCode::Enter => self.lock_authored_puzzle(),
+            KeyCode::Esc | KeyCode::Char('q') => self.screen = Screen::Menu,
+            _ => {}
+        }
+    }
 
-    use super::*;
-    use ratatui::style::Style;
+    /// Places `digit` as a `Given` at the cursor (`0` clears it back to
+    /// empty), ignoring digits too large for the board's size.
+    fn author_cell(&mut self, digit: u8) {
+        if digit as usize > self.game.board().size().side() {
+            return;
+        }
+        let (row, col) = self.game.cursor();
+        let cell = if digit == 0 { Cell::Empty } else { Cell::Given(digit) };
+        self.game.board_mut().set(row, col, cell);
+    }
 
-    #[test]
-    fn render() {
-        let app = App::default();
-        let mut buf = Buffer::empty(Rect::new(0, 0, 55, 18));
+    /// Locks the authored puzzle in and starts playing it, provided it has
+    /// exactly one solution; otherwise leaves it open for more edits with a
+    /// status message explaining why.
+    fn lock_authored_puzzle(&mut self) {
+        match solver::count_solutions(self.game.board(), 2) {
+            1 => {}
+            0 => return self.set_status("No solution — add or fix a given", DEFAULT_STATUS_TTL),
+            _ => return self.set_status("More than one solution — add another given", DEFAULT_STATUS_TTL),
+        }
+        self.difficulty = rating::rate(self.game.board());
+        self.puzzle_rating = self.difficulty;
+        self.game.set_solution(solver::solve(self.game.board()));
+        self.start_board = Some(self.game.board().clone());
+        self.move_history.clear();
+        self.game.set_cursor((0, 0));
+        self.game.reset_moves();
+        self.game.reset_mistakes();
+        self.time_limit = self.countdown_mode.then_some(DEFAULT_TIME_LIMIT);
+        self.lives_remaining = STARTING_LIVES;
+        self.screen = Screen::Playing;
+        self.start_timer();
+    }
 
-        app.render(buf.area, &mut buf);
+    /// Handles input on the stats screen: any key returns to the menu.
+    fn handle_stats_key(&mut self, _key_event: KeyEvent) {
+        self.screen = Screen::Menu;
+    }
 
-        let mut expected = Buffer::with_lines(vec![
-            "┏━━━━━━━━━━━━━━━━┓ Welcome to Rusuku ┏━━━━━━━━━━━━━━━━┓",
-            "┃                ┃       00:00       ┃                ┃",
-            "┗━━━━━━━━━━━━━━━━┛━━━━━━━━━━━━━━━━━━━┗━━━━━━━━━━━━━━━━┛",
-            "┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┃                                                     ┃",
-            "┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛",
-        ]);
-        let title_style = Style::new().bold();
-        let timer_style = Style::new().yellow().bold();
+    /// Handles input on the load screen: up/down move the highlighted slot,
+    /// Enter loads it and starts playing, Esc or `q` returns to the menu.
+    fn handle_load_key(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.load_selection = self.load_selection.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if !self.slots.is_empty() => {
+                self.load_selection = (self.load_selection + 1).min(self.slots.len() - 1);
+            }
+            KeyCode::Enter => self.load_selected_slot(),
+            KeyCode::Esc | KeyCode::Char('q') => self.screen = Screen::Menu,
+            _ => {}
+        }
+    }
+
+    /// Handles input on the library screen: up/down move the highlighted
+    /// puzzle, Enter loads it and starts playing, Esc or `q` returns to the
+    /// menu.
+    fn handle_library_key(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.library_selection = self.library_selection.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if !self.library.is_empty() => {
+                self.library_selection = (self.library_selection + 1).min(self.library.len() - 1);
+            }
+            KeyCode::Enter => self.load_selected_library_puzzle(),
+            KeyCode::Esc | KeyCode::Char('q') => self.screen = Screen::Menu,
+            _ => {}
+        }
+    }
+
+    /// Handles input on the settings screen: up/down move the highlighted
+    /// row, Enter or Space toggles (or cycles) it and immediately persists
+    /// the change to its config file, Esc or `q` returns to the menu.
+    fn handle_settings_key(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.settings_selection = self.settings_selection.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.settings_selection = (self.settings_selection + 1).min(SETTINGS_ROWS - 1);
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => self.toggle_selected_setting(),
+            KeyCode::Esc | KeyCode::Char('q') => self.screen = Screen::Menu,
+            _ => {}
+        }
+    }
+
+    /// Toggles (or cycles) whichever setting `settings_selection` points at,
+    /// then writes the config file it lives in back out.
+    fn toggle_selected_setting(&mut self) {
+        match self.settings_selection {
+            0 => {
+                self.theme_name = self.theme_name.next();
+                self.theme = Theme::named(self.theme_name);
+                let _ = theme::save(self.theme_name, &self.theme_path);
+                return;
+            }
+            1 => self.highlight_peers = !self.highlight_peers,
+            2 => self.auto_notes = !self.auto_notes,
+            3 => {
+                self.settings.advance_on_fill = match self.settings.advance_on_fill {
+                    AdvanceOnFill::Off => AdvanceOnFill::NextCell,
+                    AdvanceOnFill::NextCell => AdvanceOnFill::NextEmpty,
+                    AdvanceOnFill::NextEmpty => AdvanceOnFill::Off,
+                };
+            }
+            4 => self.settings.bell_enabled = !self.settings.bell_enabled,
+            5 => self.hide_timer = !self.hide_timer,
+            _ => {
+                let next = (DIFFICULTIES.iter().position(|&d| d == self.settings.default_difficulty).unwrap_or(0) + 1)
+                    % DIFFICULTIES.len();
+                self.settings.default_difficulty = DIFFICULTIES[next];
+            }
+        }
+        let _ = self.settings.save(&self.settings_path);
+    }
+
+    /// Handles input on the post-game replay screen: right/left (or `l`/`h`)
+    /// step forward/backward through the recorded move history, Esc or `q`
+    /// returns to the win screen.
+    fn handle_replay_key(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.replay_step = (self.replay_step + 1).min(self.move_history.len());
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.replay_step = self.replay_step.saturating_sub(1);
+            }
+            KeyCode::Esc | KeyCode::Char('q') => self.screen = Screen::Won,
+            _ => {}
+        }
+    }
+
+    /// The board as it looked after the first `replay_step` recorded moves
+    /// were applied to the game's starting snapshot.
+    fn replay_board(&self) -> Board {
+        let mut board = self.start_board.clone().unwrap_or_else(|| self.game.board().clone());
+        for (_, mv) in self.move_history.iter().take(self.replay_step) {
+            board.restore(mv.row, mv.col, mv.next.cell, mv.next.notes.clone());
+        }
+        board
+    }
+
+    /// Opens the post-game replay screen at the puzzle's starting position,
+    /// before any recorded moves.
+    fn open_replay(&mut self) {
+        self.replay_step = 0;
+        self.screen = Screen::Replay;
+    }
+
+    /// Opens the post-game dwell-time heatmap.
+    fn open_heatmap(&mut self) {
+        self.screen = Screen::Heatmap;
+    }
+
+    /// Handles input on the heatmap screen: any key returns to the win
+    /// screen.
+    fn handle_heatmap_key(&mut self, _key_event: KeyEvent) {
+        self.screen = Screen::Won;
+    }
+
+    /// Whether `code` moves the cursor, either hardcoded (the arrow keys) or
+    /// via a bound `Action`. Used to decide when a pending count prefix
+    /// should be dropped rather than consumed.
+    fn is_movement_key(&self, code: KeyCode) -> bool {
+        matches!(code, KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right)
+            || matches!(
+                self.key_bindings.action_for(code),
+                Some(Action::MoveUp | Action::MoveDown | Action::MoveLeft | Action::MoveRight)
+            )
+    }
+
+    /// Handles input during play. The arrow keys and digits are always
+    /// active; everything else is dispatched through `key_bindings` so it
+    /// can be remapped.
+    fn handle_game_key(&mut self, key_event: KeyEvent) {
+        let size = self.game.board().size();
+        let is_digit = matches!(key_event.code, KeyCode::Char(c) if char_to_digit(c, size).is_some());
+        let is_cursor_move = matches!(key_event.code, KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right)
+            || matches!(
+                self.key_bindings.action_for(key_event.code),
+                Some(Action::MoveUp | Action::MoveDown | Action::MoveLeft | Action::MoveRight)
+            );
+        // A manual pause (unlike an auto-pause from losing focus, or the
+        // timer simply not running because the game already ended) is meant
+        // to be dismissed by just picking play back up, not by remembering
+        // to press `c` first.
+        if self.screen == Screen::Playing && !self.is_timer_running && !self.focus_paused && (is_digit || is_cursor_move)
+        {
+            self.continue_timer();
+        }
+        let arms_go_to_box = self.key_bindings.action_for(key_event.code) == Some(Action::GoToBox);
+        let arms_digit_focus = self.key_bindings.action_for(key_event.code) == Some(Action::DigitFocus);
+        let arms_annotate = self.key_bindings.action_for(key_event.code) == Some(Action::Annotate);
+        let is_hint = self.key_bindings.action_for(key_event.code) == Some(Action::Hint);
+        if !self.is_movement_key(key_event.code) && !is_digit {
+            self.pending_count = None;
+        }
+        if !is_digit && !arms_go_to_box {
+            self.go_to_box_mode = false;
+        }
+        if !is_digit && !arms_digit_focus {
+            self.digit_focus_mode = false;
+        }
+        if !is_digit && !arms_annotate {
+            self.annotate_mode = false;
+        }
+        if !is_hint {
+            self.pending_hint = None;
+        }
+
+        match key_event.code {
+            KeyCode::Up => return self.move_cursor(-1, 0),
+            KeyCode::Down => return self.move_cursor(1, 0),
+            KeyCode::Left => return self.move_cursor(0, -1),
+            KeyCode::Right => return self.move_cursor(0, 1),
+            // Numpad digits arrive as the same `Char` codes as the top-row
+            // digits (just with `KeyEventState::KEYPAD` set), so this one
+            // arm already fills cells from either. A nonzero digit also
+            // arms a vim-style count prefix for the next cursor movement
+            // (e.g. `3j`), consumed by `move_cursor` — unless `go_to_box_mode`
+            // or `digit_focus_mode` is armed, in which case the digit jumps
+            // to a box or toggles scan focus instead. On the 16x16 hex
+            // variant, `A`-`G` are digits 10-16 too, which shadows those
+            // letters' usual bindings (`GoToBox`, `DigitFocus`,
+            // `AutoCandidates`, `FillCellNotes`, `HiddenSingles`) while
+            // playing that size — there are only 26 letters and this board
+            // needs sixteen of them for entry.
+            KeyCode::Char(c) if char_to_digit(c, size).is_some() => {
+                let digit = char_to_digit(c, size).unwrap();
+                if self.go_to_box_mode {
+                    self.go_to_box_mode = false;
+                    return self.go_to_box(digit as usize);
+                }
+                if self.digit_focus_mode {
+                    self.digit_focus_mode = false;
+                    return self.toggle_digit_focus(digit);
+                }
+                if self.annotate_mode {
+                    self.annotate_mode = false;
+                    return self.set_cell_annotation(digit);
+                }
+                self.pending_count = (digit != 0).then_some(digit as u32);
+                return self.fill_cell(digit);
+            }
+            KeyCode::Tab => return self.jump_to_empty_cell(1),
+            KeyCode::BackTab => return self.jump_to_empty_cell(-1),
+            KeyCode::Char('y') if self.screen == Screen::Won => return self.open_replay(),
+            KeyCode::Char('w') if self.screen == Screen::Won => return self.share_result(),
+            KeyCode::Char('h') if self.screen == Screen::Won => return self.open_heatmap(),
+            _ => {}
+        }
+
+        let Some(action) = self.key_bindings.action_for(key_event.code) else {
+            return;
+        };
+        match action {
+            Action::MoveUp => self.move_cursor(-1, 0),
+            Action::MoveDown => self.move_cursor(1, 0),
+            Action::MoveLeft => self.move_cursor(0, -1),
+            Action::MoveRight => self.move_cursor(0, 1),
+            Action::Pause => self.stop_timer(),
+            Action::Continue => self.continue_timer(),
+            Action::Solve => self.solve_board(),
+            Action::ToggleNotes => self.toggle_notes_mode(),
+            Action::TogglePeerHighlight => self.highlight_peers = !self.highlight_peers,
+            Action::ToggleCoaching => self.coaching_mode = !self.coaching_mode,
+            Action::ToggleTimerVisibility => self.hide_timer = !self.hide_timer,
+            Action::GoToBox => self.go_to_box_mode = true,
+            Action::DigitFocus => self.digit_focus_mode = true,
+            Action::Annotate => self.annotate_mode = true,
+            Action::CheckProgress => self.check_progress(),
+            Action::VerifyNotes => self.verify_notes(),
+            Action::AutoCandidates => self.auto_candidates(),
+            Action::FillCellNotes => self.fill_cell_notes(),
+            Action::NakedSingles => self.solve_naked_singles(),
+            Action::HiddenSingles => self.solve_hidden_singles(),
+            Action::PointingPairs => self.eliminate_pointing_pairs(),
+            Action::HiddenPairs => self.eliminate_hidden_pairs(),
+            Action::XWing => self.eliminate_x_wing(),
+            Action::JumpToConflict => self.jump_to_conflict(),
+            Action::Undo => self.undo(),
+            Action::UndoAll => self.undo_all(),
+            Action::Redo if key_event.modifiers.contains(KeyModifiers::CONTROL) => self.redo(),
+            Action::Redo => {}
+            Action::Save => self.save_game(),
+            Action::SaveSlot => self.save_to_slot(),
+            Action::Hint => self.hint(),
+            Action::PeekSolution => self.toggle_peek(),
+            Action::ImportFromClipboard => self.import_from_clipboard(),
+            Action::ExportAscii => self.export_ascii(),
+            Action::ResetToGivens => self.request_reset(),
+            Action::Restart => self.request_restart(),
+            Action::Quit => self.request_quit(),
+        }
+    }
+
+    /// Steps the board back to the previous state, if there's history. A
+    /// grouped move (e.g. an auto-candidates sweep) reverts every cell it
+    /// touched in one step.
+    fn undo(&mut self) {
+        if let Some(group) = self.undo_stack.undo() {
+            for mv in group.0 {
+                self.game.board_mut().restore(mv.row, mv.col, mv.prev.cell, mv.prev.notes);
+            }
+        }
+    }
+
+    /// Undoes every move in the history in one keystroke, leaving only the
+    /// puzzle's givens behind. Unlike `reset_to_givens`, each undone move
+    /// stays on the redo stack, so the whole session can be replayed
+    /// forward with `redo`.
+    fn undo_all(&mut self) {
+        while let Some(group) = self.undo_stack.undo() {
+            for mv in group.0 {
+                self.game.board_mut().restore(mv.row, mv.col, mv.prev.cell, mv.prev.notes);
+            }
+        }
+    }
+
+    /// Re-applies a move (or group) undone by `undo`, if there's redo
+    /// history.
+    fn redo(&mut self) {
+        if let Some(group) = self.undo_stack.redo() {
+            for mv in group.0 {
+                self.game.board_mut().restore(mv.row, mv.col, mv.next.cell, mv.next.notes);
+            }
+        }
+    }
+
+    /// Toggles notes mode, which redirects digit keys to pencil marks
+    /// instead of filling cells.
+    fn toggle_notes_mode(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        self.notes_mode = !self.notes_mode;
+    }
+
+    /// Fills the selected cell with `digit`, or clears it if `digit` is 0.
+    /// Givens can't be overwritten. In notes mode, a digit toggles a
+    /// pencil-mark candidate on an empty cell instead. Digits beyond the
+    /// board's size (e.g. `5` on a 4x4 board) are ignored. In strict mode, a
+    /// digit that contradicts the puzzle's solution is rejected the same way
+    /// a given overwrite is: flashed, never placed. In lives mode, a wrong
+    /// digit is placed but costs a life, ending the game once none are left.
+    fn fill_cell(&mut self, digit: u8) {
+        if !self.is_timer_running || digit as usize > self.game.board().size().side() {
+            return;
+        }
+        let (row, col) = self.game.cursor();
+        if matches!(self.game.board().get(row, col), Cell::Given(_)) {
+            self.flash = Some(((row, col), self.clock.now() + FLASH_DURATION));
+            self.ring_bell();
+            return;
+        }
+        if !self.notes_mode && digit != 0 && self.is_wrong_digit(row, col, digit) {
+            self.flash = Some(((row, col), self.clock.now() + FLASH_DURATION));
+            self.game.add_mistake();
+            self.ring_bell();
+            return;
+        }
+
+        let prev = self.cell_state(row, col);
+        let contradicts_solution = digit != 0 && self.contradicts_solution(row, col, digit);
+        let units = units_at(row, col, self.game.board().size());
+        let was_complete: Vec<bool> =
+            units.iter().map(|unit| unit_is_complete_and_correct(self.game.board(), unit)).collect();
+
+        if self.notes_mode {
+            if digit == 0 {
+                return;
+            }
+            self.game.board_mut().toggle_note(row, col, digit);
+        } else if digit == 0 {
+            self.game.clear();
+        } else {
+            self.game.fill(digit);
+        }
+
+        self.record_move(row, col, prev.clone());
+
+        if !self.notes_mode {
+            if digit == 0 {
+                if let Some(cleared) = prev.cell.digit() {
+                    self.sync_peer_notes(row, col, cleared, false);
+                }
+            } else {
+                self.sync_peer_notes(row, col, digit, true);
+            }
+        }
+
+        if !self.notes_mode && contradicts_solution {
+            self.game.add_mistake();
+            if self.lives_mode {
+                self.lose_life();
+            }
+        }
+
+        if !self.notes_mode && digit != 0 && !contradicts_solution {
+            let newly_completed: Vec<(usize, usize)> = units
+                .into_iter()
+                .zip(was_complete)
+                .filter(|(unit, was_complete)| !was_complete && unit_is_complete_and_correct(self.game.board(), unit))
+                .flat_map(|(unit, _)| unit)
+                .collect();
+            if !newly_completed.is_empty() {
+                self.unit_flash = Some((newly_completed, self.clock.now() + UNIT_FLASH_DURATION));
+            }
+        }
+
+        if !self.notes_mode && digit != 0 {
+            self.advance_cursor_after_fill();
+        }
+    }
+
+    /// Moves the cursor on from a just-filled cell, per `Settings::advance_on_fill`.
+    fn advance_cursor_after_fill(&mut self) {
+        let side = self.game.board().size().side() as isize;
+        match self.settings.advance_on_fill {
+            AdvanceOnFill::Off => {}
+            AdvanceOnFill::NextCell => {
+                let total = side * side;
+                let (row, col) = self.game.cursor();
+                let index = (row as isize * side + col as isize + 1).rem_euclid(total);
+                self.game.set_cursor(((index / side) as usize, (index % side) as usize));
+            }
+            AdvanceOnFill::NextEmpty => self.jump_to_empty_cell(1),
+        }
+    }
+
+    /// When `auto_notes` is on, keeps peers whose pencil marks are already
+    /// populated in sync with a digit placed or cleared at `(row, col)`:
+    /// placing `digit` removes it from every such peer that still lists
+    /// it; clearing it back to `Cell::Empty` restores it to every such
+    /// peer for which it's legal again. Peers the player hasn't pencilled
+    /// anything into are left alone, the same convention
+    /// `eliminate_pointing_pairs` uses for what counts as managed notes.
+    fn sync_peer_notes(&mut self, row: usize, col: usize, digit: u8, placed: bool) {
+        if !self.auto_notes {
+            return;
+        }
+        for (peer_row, peer_col) in unit_cells(row, col, self.game.board().size()) {
+            if self.game.board().get(peer_row, peer_col) != Cell::Empty
+                || self.game.board().notes(peer_row, peer_col).is_empty()
+            {
+                continue;
+            }
+            let has_digit = self.game.board().notes(peer_row, peer_col).contains(&digit);
+            let should_toggle = if placed {
+                has_digit
+            } else {
+                !has_digit && self.game.board().candidates(peer_row, peer_col).contains(&digit)
+            };
+            if should_toggle {
+                let prev = self.cell_state(peer_row, peer_col);
+                self.game.board_mut().toggle_note(peer_row, peer_col, digit);
+                self.record_move(peer_row, peer_col, prev);
+            }
+        }
+    }
+
+    /// Whether placing `digit` at `(row, col)` would contradict the current
+    /// puzzle's solution. Always `false` before a solution has been
+    /// computed.
+    fn contradicts_solution(&self, row: usize, col: usize, digit: u8) -> bool {
+        self.game
+            .solution()
+            .is_some_and(|solution| solution.get(row, col).digit() != Some(digit))
+    }
+
+    /// Whether placing `digit` at `(row, col)` should be rejected outright.
+    /// Only strict mode rejects entries; lives mode lets them stand and
+    /// deducts a life instead.
+    fn is_wrong_digit(&self, row: usize, col: usize, digit: u8) -> bool {
+        self.strict_mode && self.contradicts_solution(row, col, digit)
+    }
+
+    /// Requests the terminal bell, if `bell_enabled` is on.
+    fn ring_bell(&self) {
+        if self.settings.bell_enabled {
+            self.feedback.bell();
+        }
+    }
+
+    /// Deducts one life for a wrong entry in lives mode, ending the game
+    /// once none are left.
+    fn lose_life(&mut self) {
+        self.lives_remaining = self.lives_remaining.saturating_sub(1);
+        if self.lives_remaining == 0 {
+            self.stop_timer();
+            self.screen = Screen::GameOver;
+        }
+    }
+
+    /// The cell currently flashing red after a rejected attempt to
+    /// overwrite a given, if the flash hasn't expired yet. Clears itself
+    /// once expired.
+    fn flashing_cell(&mut self) -> Option<(usize, usize)> {
+        let (cell, until) = self.flash?;
+        if self.clock.now() >= until {
+            self.flash = None;
+            return None;
+        }
+        Some(cell)
+    }
+
+    /// The cells currently flashing green after completing a row, column,
+    /// or box, if the flash hasn't expired yet. Clears itself once expired.
+    fn flashing_units(&mut self) -> std::collections::HashSet<(usize, usize)> {
+        let Some((cells, until)) = &self.unit_flash else {
+            return std::collections::HashSet::new();
+        };
+        if self.clock.now() >= *until {
+            self.unit_flash = None;
+            return std::collections::HashSet::new();
+        }
+        cells.iter().copied().collect()
+    }
+
+    /// Shows `msg` below the grid until `ttl` elapses, replacing any status
+    /// already showing.
+    fn set_status(&mut self, msg: impl Into<String>, ttl: Duration) {
+        self.status = Some((msg.into(), self.clock.now() + ttl));
+    }
+
+    /// The current status message, if it hasn't expired yet. Clears itself
+    /// once expired.
+    fn status_message(&mut self) -> Option<String> {
+        let (message, until) = self.status.clone()?;
+        if self.clock.now() >= until {
+            self.status = None;
+            return None;
+        }
+        Some(message)
+    }
+
+    /// Whether the cursor should be drawn highlighted on this frame: steady
+    /// on while the game is paused, otherwise alternating roughly every
+    /// `CURSOR_BLINK_INTERVAL`.
+    fn cursor_blink_on(&self) -> bool {
+        let Some(start_time) = self.start_time else {
+            return true;
+        };
+        let phase = self.clock.now().duration_since(start_time).as_millis() / CURSOR_BLINK_INTERVAL.as_millis();
+        phase.is_multiple_of(2)
+    }
+
+    fn cell_state(&self, row: usize, col: usize) -> CellState {
+        CellState {
+            cell: self.game.board().get(row, col),
+            notes: self.game.board().notes(row, col).clone(),
+        }
+    }
+
+    /// Saves the current board and elapsed time to the default save path.
+    fn save_game(&mut self) {
+        let result = persistence::save(self.game.board(), self.elapsed(), &persistence::default_save_path());
+        if result.is_ok() {
+            self.set_status("Saved", DEFAULT_STATUS_TTL);
+        }
+    }
+
+    /// Saves the current board, elapsed time, and difficulty to a new named
+    /// slot, alongside (not replacing) the single autosave `save_game`
+    /// writes. Slots are named by when they were saved, since there's
+    /// nowhere in this UI to type a name.
+    fn save_to_slot(&self) {
+        let name = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|since_epoch| since_epoch.as_secs().to_string())
+            .unwrap_or_else(|_| "0".to_string());
+        let _ =
+            persistence::save_slot(&name, self.game.board(), self.elapsed(), self.difficulty, &persistence::default_slots_dir());
+    }
+
+    /// Refreshes `slots` from disk and opens the load screen.
+    fn open_load_screen(&mut self) {
+        self.slots = persistence::list_slots(&persistence::default_slots_dir());
+        self.load_selection = 0;
+        self.screen = Screen::Load;
+    }
+
+    /// Restores the highlighted slot's board, elapsed time, and difficulty,
+    /// and goes straight to the (paused) board, the same way restoring the
+    /// autosave does.
+    fn load_selected_slot(&mut self) {
+        let Some(slot) = self.slots.get(self.load_selection) else {
+            return;
+        };
+        if let Ok((board, elapsed, difficulty)) = persistence::load_slot(&slot.name, &persistence::default_slots_dir()) {
+            *self.game.board_mut() = board;
+            self.elapsed_time = elapsed;
+            self.difficulty = difficulty;
+            self.screen = Screen::Playing;
+        }
+    }
+
+    /// Loads the bundled library (if not already loaded) and opens the
+    /// library screen.
+    fn open_library_screen(&mut self) {
+        if self.library.is_empty() {
+            self.library = library::load();
+        }
+        self.library_selection = 0;
+        self.screen = Screen::Library;
+    }
+
+    /// Starts a fresh game on the highlighted library puzzle's givens, the
+    /// same way `start_game` starts a freshly generated one.
+    fn load_selected_library_puzzle(&mut self) {
+        let Some(puzzle) = self.library.get(self.library_selection) else {
+            return;
+        };
+        let Ok(board) = puzzle.board() else {
+            return;
+        };
+        *self.game.board_mut() = board;
+        self.difficulty = puzzle.difficulty;
+        self.puzzle_rating = rating::rate(self.game.board());
+        self.game.set_solution(solver::solve(self.game.board()));
+        self.start_board = Some(self.game.board().clone());
+        self.move_history.clear();
+        self.game.set_cursor((0, 0));
+        self.game.reset_moves();
+        self.game.reset_mistakes();
+        self.time_limit = self.countdown_mode.then_some(DEFAULT_TIME_LIMIT);
+        self.lives_remaining = STARTING_LIVES;
+        self.screen = Screen::Playing;
+        self.start_timer();
+    }
+
+    /// Parses a puzzle line off the clipboard and starts a fresh game from
+    /// it, the same way `load_selected_library_puzzle` starts one from the
+    /// library. Reports a status message instead of starting a game if
+    /// there's nothing to paste or it doesn't parse as a puzzle.
+    fn import_from_clipboard(&mut self) {
+        let Some(text) = self.clipboard.paste() else {
+            self.set_status("Clipboard is empty", DEFAULT_STATUS_TTL);
+            return;
+        };
+        let Ok(board) = text.trim().parse::<Board>() else {
+            self.set_status("Clipboard text isn't a valid puzzle", DEFAULT_STATUS_TTL);
+            return;
+        };
+        if let Err(conflicting) = board.givens_are_valid() {
+            self.set_status(format!("Clipboard puzzle has conflicting givens at {conflicting:?}"), DEFAULT_STATUS_TTL);
+            return;
+        }
+        self.difficulty = rating::rate(&board);
+        *self.game.board_mut() = board;
+        self.puzzle_rating = rating::rate(self.game.board());
+        self.game.set_solution(solver::solve(self.game.board()));
+        self.start_board = Some(self.game.board().clone());
+        self.move_history.clear();
+        self.game.set_cursor((0, 0));
+        self.game.reset_moves();
+        self.game.reset_mistakes();
+        self.time_limit = self.countdown_mode.then_some(DEFAULT_TIME_LIMIT);
+        self.lives_remaining = STARTING_LIVES;
+        self.screen = Screen::Playing;
+        self.start_timer();
+        let status = match solver::count_solutions(self.game.board(), 2) {
+            0 => "Imported puzzle from clipboard (warning: no solution)",
+            1 => "Imported puzzle from clipboard",
+            _ => "Imported puzzle from clipboard (warning: more than one solution)",
+        };
+        self.set_status(status, DEFAULT_STATUS_TTL);
+    }
+
+    /// Writes a boxed ASCII rendering of the current board to the default
+    /// export path, so a player can paste it into chat or a text file.
+    fn export_ascii(&self) {
+        let _ = persistence::export_ascii(self.game.board(), &persistence::default_export_path());
+    }
+
+    /// A spoiler-free, Wordle-style summary of a finished game, e.g.
+    /// `"Rusuku Hard 07:43 ✅ 0 hints, 0 mistakes"` — safe to paste into chat
+    /// since it reveals nothing about the solved grid.
+    fn result_summary(&self) -> String {
+        let minutes = self.elapsed().as_secs() / 60;
+        let seconds = self.elapsed().as_secs() % 60;
+        format!(
+            "Rusuku {:?} {:02}:{:02} ✅ {} hints, {} mistakes",
+            self.difficulty, minutes, seconds, self.hints_used, self.game.mistakes()
+        )
+    }
+
+    /// Writes `result_summary` to the default result export path, so a
+    /// player can share it without exposing the solved grid.
+    fn share_result(&self) {
+        let _ = persistence::export_result_summary(&self.result_summary(), &persistence::default_result_summary_path());
+    }
+
+    /// Opens the "erase all my entries" confirmation prompt, since resetting
+    /// the puzzle can't be undone.
+    fn request_reset(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        self.confirm_reset = true;
+    }
+
+    /// Handles Ctrl+C: unlike `request_quit`, this never opens a
+    /// confirmation prompt, since an interrupt is asking to leave right now.
+    /// Autosaves first if there's any progress worth keeping, then exits so
+    /// `main` can restore the terminal before the process ends.
+    fn handle_interrupt(&mut self) {
+        if self.is_timer_running {
+            self.save_game();
+        }
+        self.exit();
+    }
+
+    /// Quits immediately if the board is fresh or already solved, since
+    /// there's nothing to lose. Otherwise opens the "quit without saving"
+    /// confirmation prompt.
+    fn request_quit(&mut self) {
+        if self.game.board().has_entries() && !self.game.is_won() {
+            self.confirm_quit = true;
+        } else {
+            self.exit();
+        }
+    }
+
+    /// Clears every player-filled digit and pencil mark, resetting the move,
+    /// hint, and mistake counters, but leaves the timer running so the
+    /// attempt still counts against the player's time.
+    fn reset_to_givens(&mut self) {
+        self.game.board_mut().reset_to_givens();
+        self.undo_stack = UndoStack::default();
+        self.game.reset_moves();
+        self.hints_used = 0;
+        self.game.reset_mistakes();
+    }
+
+    /// Opens the "restart from scratch" confirmation prompt, since it can't
+    /// be undone.
+    fn request_restart(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        self.confirm_restart = true;
+    }
+
+    /// Retries the same puzzle from a clean slate: clears every entry like
+    /// `reset_to_givens`, and also zeroes the elapsed time and restarts the
+    /// clock, for a player who wants a fresh attempt at their time rather
+    /// than just their move count.
+    fn restart(&mut self) {
+        self.reset_to_givens();
+        self.elapsed_time = Duration::ZERO;
+        self.start_time = Some(self.clock.now());
+    }
+
+    /// Fills the board with the solved state, if one exists.
+    fn solve_board(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        if let Some(solution) = solver::solve(self.game.board()) {
+            *self.game.board_mut() = solution;
+        }
+    }
+
+    /// Identifies a hint but doesn't apply it yet, so the player can read
+    /// what it's about to do. A second press of the same key carries it
+    /// out. A no-op (dropping any stale pending hint) if the board is
+    /// already full or unsolvable.
+    ///
+    /// The first press picks the simplest technique that makes progress,
+    /// checked in the order a human would reach for them: a naked single,
+    /// then a hidden single, then a pointing pair elimination. If none of
+    /// those apply, it falls back to `solver::solve`'s most-constrained
+    /// cell, so a hint is always available even on puzzles that need
+    /// harder techniques than those three.
+    fn hint(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        if let Some(pending) = self.pending_hint.take() {
+            self.apply_hint(pending);
+            return;
+        }
+        self.pending_hint = self.next_hint();
+        if self.pending_hint.is_none() {
+            self.set_status("No hints left", DEFAULT_STATUS_TTL);
+        }
+    }
+
+    /// Finds the simplest technique that makes progress on the current
+    /// board, in the order described on `hint`.
+    fn next_hint(&self) -> Option<PendingHint> {
+        let side = self.game.board().size().side();
+
+        for row in 0..side {
+            for col in 0..side {
+                if self.game.board().get(row, col) != Cell::Empty {
+                    continue;
+                }
+                let candidates = self.game.board().candidates(row, col);
+                if candidates.len() == 1 {
+                    let digit = *candidates.iter().next().unwrap();
+                    return Some(PendingHint {
+                        technique: "naked single",
+                        explanation: format!("({row}, {col}) can only legally hold {digit}"),
+                        cells: [(row, col)].into_iter().collect(),
+                        action: HintAction::Fill { row, col, digit },
+                    });
+                }
+            }
+        }
+
+        if let Some(&(row, col, digit)) = self.game.board().hidden_singles().first() {
+            return Some(PendingHint {
+                technique: "hidden single",
+                explanation: format!("{digit} can only go in ({row}, {col}) within its row, column, or box"),
+                cells: [(row, col)].into_iter().collect(),
+                action: HintAction::Fill { row, col, digit },
+            });
+        }
+
+        if let Some(elimination) = self
+            .game
+            .board()
+            .pointing_pairs()
+            .into_iter()
+            .find(|e| self.game.board().notes(e.row, e.col).contains(&e.digit))
+        {
+            let Elimination { row, col, digit } = elimination;
+            return Some(PendingHint {
+                technique: "pointing pair",
+                explanation: format!("{digit} is confined to one line within a box, so it can't also go in ({row}, {col})"),
+                cells: [(row, col)].into_iter().collect(),
+                action: HintAction::EliminateNote { row, col, digit },
+            });
+        }
+
+        let (row, col, _) = solver::most_constrained_empty_cell(self.game.board())?;
+        let solution = solver::solve(self.game.board())?;
+        let digit = solution
+            .get(row, col)
+            .digit()
+            .expect("the solved board has no empty cells");
+        Some(PendingHint {
+            technique: "solved cell",
+            explanation: format!("({row}, {col}) must be {digit}, though it takes deeper solving to see why"),
+            cells: [(row, col)].into_iter().collect(),
+            action: HintAction::Fill { row, col, digit },
+        })
+    }
+
+    /// Carries out a hint the player has already been shown, as a normal
+    /// (undoable) move.
+    fn apply_hint(&mut self, pending: PendingHint) {
+        match pending.action {
+            HintAction::Fill { row, col, digit } => {
+                let prev = self.cell_state(row, col);
+                self.game.board_mut().set(row, col, Cell::Filled(digit));
+                self.record_move(row, col, prev);
+            }
+            HintAction::EliminateNote { row, col, digit } => {
+                if self.game.board().notes(row, col).contains(&digit) {
+                    let prev = self.cell_state(row, col);
+                    self.game.board_mut().toggle_note(row, col, digit);
+                    self.record_move(row, col, prev);
+                }
+            }
+        }
+        self.hints_used += 1;
+    }
+
+    /// Switches the full-solution overlay on or off. Turning it on counts
+    /// as a peek toward the score, the same as a hint; turning it back off
+    /// is free.
+    fn toggle_peek(&mut self) {
+        self.peek_solution = !self.peek_solution;
+        if self.peek_solution {
+            self.peeks_used += 1;
+        }
+    }
+
+    /// Flags every player-filled cell whose value doesn't match the
+    /// puzzle's solution, leaving givens and empty cells untouched. This
+    /// isn't an undoable move: it only marks cells, and each mark clears
+    /// itself the next time that cell is edited.
+    ///
+    /// The solution is derived from the givens alone, not the current
+    /// board, since a wrong entry may otherwise leave the board with a
+    /// duplicate digit that the solver would reject as unsolvable.
+    fn check_progress(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        let side = self.game.board().size().side();
+        let mut givens_only = Board::new(self.game.board().size());
+        givens_only.set_diagonal(self.game.board().is_diagonal());
+        for row in 0..side {
+            for col in 0..side {
+                if let Cell::Given(digit) = self.game.board().get(row, col) {
+                    givens_only.set(row, col, Cell::Given(digit));
+                }
+            }
+        }
+        let Some(solution) = solver::solve(&givens_only) else {
+            return;
+        };
+
+        for row in 0..side {
+            for col in 0..side {
+                if let Cell::Filled(digit) = self.game.board().get(row, col) {
+                    if solution.get(row, col).digit() != Some(digit) {
+                        self.game.board_mut().mark_wrong(row, col);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Flags every empty cell whose pencil marks contain a digit that can't
+    /// be the answer, i.e. isn't the puzzle's solution value for that cell,
+    /// using the solution computed when the game started. A strong hint,
+    /// so it's opt-in like `check progress`, and shares the same marking:
+    /// each flag clears itself the next time that cell is edited.
+    fn verify_notes(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        let Some(solution) = self.game.solution().cloned() else {
+            return;
+        };
+        let side = self.game.board().size().side();
+        for row in 0..side {
+            for col in 0..side {
+                if self.game.board().get(row, col) != Cell::Empty {
+                    continue;
+                }
+                let correct = solution.get(row, col).digit();
+                let has_wrong_note = self.game.board().notes(row, col).iter().any(|&digit| Some(digit) != correct);
+                if has_wrong_note {
+                    self.game.board_mut().mark_wrong(row, col);
+                }
+            }
+        }
+    }
+
+    /// Fills every empty cell's pencil marks with its legal candidates,
+    /// discarding whatever notes were there before. Re-running it recomputes
+    /// from scratch, so it stays correct as the board changes. Every cell it
+    /// touches undoes as a single atomic step, via `MoveGroup`.
+    fn auto_candidates(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        let side = self.game.board().size().side();
+        let mut moves = Vec::new();
+        for row in 0..side {
+            for col in 0..side {
+                if self.game.board().get(row, col) == Cell::Empty {
+                    let prev = self.cell_state(row, col);
+                    let candidates = self.game.board().candidates(row, col);
+                    self.game.board_mut().restore(row, col, Cell::Empty, candidates);
+                    let next = self.cell_state(row, col);
+                    if next != prev {
+                        moves.push(Move { row, col, prev, next });
+                    }
+                }
+            }
+        }
+        if !moves.is_empty() {
+            self.undo_stack.push_group(MoveGroup(moves));
+        }
+    }
+
+    /// Sets the selected cell's pencil marks to exactly its legal
+    /// candidates, discarding whatever notes were there before. Does
+    /// nothing if the cell already holds a digit. Unlike `auto_candidates`,
+    /// this only touches the one selected cell.
+    fn fill_cell_notes(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        let (row, col) = self.game.cursor();
+        if self.game.board().get(row, col) != Cell::Empty {
+            return;
+        }
+        let candidates = self.game.board().candidates(row, col);
+        self.game.board_mut().restore(row, col, Cell::Empty, candidates);
+    }
+
+    /// Moves the cursor to the next empty cell in reading order (or the
+    /// previous one, for a negative `direction`), wrapping past the last
+    /// cell back to the first. A full board leaves the cursor where it is.
+    ///
+    /// While a digit focus is active, this instead cycles only through the
+    /// empty cells where that digit is still a legal candidate, so a player
+    /// scanning for a number's placements can walk every option with `Tab`.
+    fn jump_to_empty_cell(&mut self, direction: isize) {
+        if !self.is_timer_running {
+            return;
+        }
+        let side = self.game.board().size().side() as isize;
+        let total = side * side;
+        let (row, col) = self.game.cursor();
+        let start = row as isize * side + col as isize;
+
+        for step in 1..=total {
+            let index = (start + direction * step).rem_euclid(total);
+            let (row, col) = ((index / side) as usize, (index % side) as usize);
+            let is_candidate_cell = self.game.board().get(row, col) == Cell::Empty
+                && self
+                    .digit_focus
+                    .is_none_or(|digit| self.game.board().candidates(row, col).contains(&digit));
+            if is_candidate_cell {
+                self.game.set_cursor((row, col));
+                return;
+            }
+        }
+    }
+
+    /// Moves the cursor to the next conflicting cell (one sharing a digit
+    /// with another cell in its row, column, or box) after the current
+    /// position in reading order, wrapping past the last one back to the
+    /// first. Does nothing if the board has no conflicts.
+    fn jump_to_conflict(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        let side = self.game.board().size().side();
+        let conflicts = self.game.board().conflicts();
+        if conflicts.is_empty() {
+            return;
+        }
+        let (row, col) = self.game.cursor();
+        let current = row * side + col;
+        let indices: Vec<usize> = conflicts.iter().map(|&(r, c)| r * side + c).collect();
+        let next = indices.iter().copied().filter(|&index| index > current).min();
+        let next = next.unwrap_or_else(|| indices.into_iter().min().unwrap());
+        self.game.set_cursor((next / side, next % side));
+    }
+
+    /// Every empty cell with exactly one legal candidate, for the coaching
+    /// overlay to highlight. Purely informational: unlike `solve_naked_singles`,
+    /// nothing gets filled in.
+    fn coaching_cells(&self) -> std::collections::HashSet<(usize, usize)> {
+        let side = self.game.board().size().side();
+        let mut cells = std::collections::HashSet::new();
+        for row in 0..side {
+            for col in 0..side {
+                if self.game.board().get(row, col) == Cell::Empty && self.game.board().candidates(row, col).len() == 1 {
+                    cells.insert((row, col));
+                }
+            }
+        }
+        cells
+    }
+
+    /// Repeatedly fills every cell with exactly one legal candidate (a
+    /// "naked single"), stopping once no more remain. Unlike `solve_board`,
+    /// this applies only this one human technique, so it stops short of
+    /// puzzles that need guessing. Each fill is a normal, undoable move.
+    fn solve_naked_singles(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        let side = self.game.board().size().side();
+        loop {
+            let mut filled_any = false;
+            for row in 0..side {
+                for col in 0..side {
+                    if self.game.board().get(row, col) != Cell::Empty {
+                        continue;
+                    }
+                    let candidates = self.game.board().candidates(row, col);
+                    if candidates.len() != 1 {
+                        continue;
+                    }
+                    let digit = *candidates.iter().next().expect("checked len == 1");
+                    let prev = self.cell_state(row, col);
+                    self.game.board_mut().set(row, col, Cell::Filled(digit));
+                    self.record_move(row, col, prev);
+                    filled_any = true;
+                }
+            }
+            if !filled_any {
+                break;
+            }
+        }
+    }
+
+    /// Repeatedly fills every cell where `Board::hidden_singles` finds a
+    /// digit confined to just that cell within its row, column, or box,
+    /// stopping once no more remain. Each fill is a normal, undoable move.
+    fn solve_hidden_singles(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        loop {
+            let hidden_singles = self.game.board().hidden_singles();
+            if hidden_singles.is_empty() {
+                break;
+            }
+            for (row, col, digit) in hidden_singles {
+                if self.game.board().get(row, col) != Cell::Empty {
+                    continue;
+                }
+                let prev = self.cell_state(row, col);
+                self.game.board_mut().set(row, col, Cell::Filled(digit));
+                self.record_move(row, col, prev);
+            }
+        }
+    }
+
+    /// Repeatedly removes candidates found by `Board::pointing_pairs` from
+    /// the affected cells' pencil marks, stopping once no more eliminations
+    /// are found. Unlike the naked- and hidden-single solvers, this never
+    /// fills a cell on its own: a pointing pair only narrows candidates. It
+    /// only has anything to remove from cells whose notes are already
+    /// populated, e.g. by `auto_candidates` or `fill_cell_notes`.
+    fn eliminate_pointing_pairs(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        loop {
+            let mut changed = false;
+            for elimination in self.game.board().pointing_pairs() {
+                let (row, col) = (elimination.row, elimination.col);
+                if self.game.board().notes(row, col).contains(&elimination.digit) {
+                    let prev = self.cell_state(row, col);
+                    self.game.board_mut().toggle_note(row, col, elimination.digit);
+                    self.record_move(row, col, prev);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    /// Same as `eliminate_pointing_pairs`, but driven by `Board::hidden_pairs`.
+    fn eliminate_hidden_pairs(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        loop {
+            let mut changed = false;
+            for elimination in self.game.board().hidden_pairs() {
+                let (row, col) = (elimination.row, elimination.col);
+                if self.game.board().notes(row, col).contains(&elimination.digit) {
+                    let prev = self.cell_state(row, col);
+                    self.game.board_mut().toggle_note(row, col, elimination.digit);
+                    self.record_move(row, col, prev);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    /// Same as `eliminate_pointing_pairs`, but driven by `Board::x_wing`.
+    fn eliminate_x_wing(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        loop {
+            let mut changed = false;
+            for elimination in self.game.board().x_wing() {
+                let (row, col) = (elimination.row, elimination.col);
+                if self.game.board().notes(row, col).contains(&elimination.digit) {
+                    let prev = self.cell_state(row, col);
+                    self.game.board_mut().toggle_note(row, col, elimination.digit);
+                    self.record_move(row, col, prev);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    /// Records a completed edit at `(row, col)` in the undo history (if it
+    /// actually changed anything) and checks whether it just won the game.
+    fn record_move(&mut self, row: usize, col: usize, prev: CellState) {
+        let next = self.cell_state(row, col);
+        if next != prev {
+            let mv = Move {
+                row,
+                col,
+                prev,
+                next,
+            };
+            self.move_history.push((self.elapsed(), mv.clone()));
+            self.undo_stack.push(mv);
+        }
+
+        if self.game.is_won() {
+            self.stop_timer();
+            self.screen = Screen::Won;
+            self.ring_bell();
+            let elapsed = self.elapsed();
+            self.is_new_record = stats::record_if_better(&mut self.best_times, self.difficulty, elapsed);
+            let _ = stats::save(&self.best_times, &stats::default_path());
+            stats::record_completion(&mut self.completion_stats, self.difficulty, elapsed);
+            let _ = stats::save_stats(&self.completion_stats, &stats::default_stats_path());
+
+            self.current_score =
+                score::compute(elapsed, self.hints_used, self.peeks_used, self.game.mistakes(), self.difficulty);
+            self.is_new_high_score =
+                stats::record_high_score(&mut self.high_scores, self.difficulty, self.current_score);
+            let _ = stats::save_high_scores(&self.high_scores, &stats::default_high_scores_path());
+
+            let date = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs().to_string();
+            let _ = stats::export_csv(
+                &stats::default_csv_path(),
+                self.difficulty,
+                elapsed,
+                self.hints_used,
+                self.game.mistakes(),
+                &date,
+            );
+        }
+    }
+
+    /// Moves the cursor by `(d_row, d_col)`, repeated by any pending count
+    /// prefix (`3` in `3j` moves three cells), clamped to the grid edges.
+    /// Ignored while the timer isn't running, so play can't start before `i`.
+    fn move_cursor(&mut self, d_row: isize, d_col: isize) {
+        if !self.is_timer_running {
+            return;
+        }
+        let count = self.pending_count.take().unwrap_or(1) as isize;
+        self.game.move_cursor(d_row * count, d_col * count);
+    }
+
+    /// Jumps the cursor to the top-left cell of the 1-indexed box
+    /// `box_number`, per `go_to_box_mode`. Out-of-range box numbers (e.g. `9`
+    /// on a 4x4 board, which only has 4 boxes) are ignored.
+    fn go_to_box(&mut self, box_number: usize) {
+        if !self.is_timer_running {
+            return;
+        }
+        if let Some(origin) = self.game.board().size().box_origin(box_number) {
+            self.game.set_cursor(origin);
+        }
+    }
+
+    /// Turns scanning focus on `digit` on, or off if it's already the
+    /// focused digit, per `digit_focus_mode`.
+    fn toggle_digit_focus(&mut self, digit: u8) {
+        self.digit_focus = if self.digit_focus == Some(digit) { None } else { Some(digit) };
+    }
+
+    /// Tags the selected cell with the highlight color `digit` (`1`-`9`)
+    /// picks, or clears it for `0`, per `annotate_mode`.
+    fn set_cell_annotation(&mut self, digit: u8) {
+        let (row, col) = self.game.cursor();
+        self.game.board_mut().set_annotation(row, col, AnnotationColor::from_digit(digit));
+    }
+
+    /// Generates a puzzle at `difficulty` and `size`, and transitions to the
+    /// playing screen, starting the timer. The solution is solved once here
+    /// rather than on demand, so strict mode can check every entry against
+    /// it without re-solving on each key press. In daily mode, `size` is
+    /// ignored in favor of `generate_seeded`'s fixed classic 9x9 board, so
+    /// everyone playing today's challenge gets the same puzzle.
+    fn start_game(&mut self, difficulty: Difficulty, size: BoardSize) {
+        *self.game.board_mut() = if self.daily_mode {
+            generator::generate_seeded(difficulty, daily_seed())
+        } else {
+            generator::generate(difficulty, size, Symmetry::default(), self.diagonal_mode)
+        };
+        self.difficulty = difficulty;
+        self.puzzle_rating = rating::rate(self.game.board());
+        self.game.set_solution(solver::solve(self.game.board()));
+        self.start_board = Some(self.game.board().clone());
+        self.move_history.clear();
+        self.game.set_cursor((0, 0));
+        self.game.reset_moves();
+        self.game.reset_mistakes();
+        self.time_limit = self.countdown_mode.then_some(DEFAULT_TIME_LIMIT);
+        self.lives_remaining = STARTING_LIVES;
+        self.screen = Screen::Playing;
+        self.start_timer();
+    }
+
+    fn start_timer(&mut self) {
+        if self.is_timer_running {
+            return;
+        }
+        self.is_timer_running = true;
+        self.start_time = Some(self.clock.now());
+    }
+
+    fn stop_timer(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        self.is_timer_running = false;
+
+        if let Some(start_time) = self.start_time {
+            self.elapsed_time += self.clock.now().duration_since(start_time).min(MAX_ELAPSED_DELTA);
+            self.start_time = None;
+        }
+    }
+
+    fn continue_timer(&mut self) {
+        if self.is_timer_running {
+            return;
+        }
+        self.start_time = Some(self.clock.now());
+        self.is_timer_running = true;
+    }
+
+    /// Pauses the timer when the terminal loses focus, e.g. an alt-tab
+    /// away, so the gap doesn't count toward the player's time.
+    fn pause_for_focus_loss(&mut self) {
+        if !self.is_timer_running {
+            return;
+        }
+        self.stop_timer();
+        self.focus_paused = true;
+    }
+
+    /// Resumes the timer once focus returns, but only if it was focus
+    /// loss (rather than a manual `p`) that paused it.
+    fn resume_from_focus_gain(&mut self) {
+        if !self.focus_paused {
+            return;
+        }
+        self.focus_paused = false;
+        self.continue_timer();
+    }
+
+    fn elapsed(&self) -> Duration {
+        if let Some(start_time) = self.start_time {
+            if self.is_timer_running {
+                return self.elapsed_time + self.clock.now().duration_since(start_time).min(MAX_ELAPSED_DELTA);
+            }
+        }
+        self.elapsed_time
+    }
+
+    /// The time left before a countdown-mode game's limit is reached, or
+    /// `None` in the normal count-up mode.
+    fn remaining(&self) -> Option<Duration> {
+        self.time_limit.map(|limit| limit.saturating_sub(self.elapsed()))
+    }
+
+    /// Ends a countdown-mode game once its limit is reached, before the
+    /// board was solved. Called every loop iteration so time runs out even
+    /// without a key press.
+    fn check_time_up(&mut self) {
+        if self.screen != Screen::Playing {
+            return;
+        }
+        if self.remaining() == Some(Duration::ZERO) {
+            self.stop_timer();
+            self.screen = Screen::TimesUp;
+        }
+    }
+
+    fn exit(&mut self) {
+        self.exit = true;
+    }
+}
+
+/// Renders `app`'s current screen into `frame`, or a friendly resize
+/// prompt in its place if the terminal is smaller than `MIN_TERMINAL_WIDTH`
+/// by `MIN_TERMINAL_HEIGHT`: every screen's layout assumes at least that
+/// much room, so drawing into anything smaller risks a panic rather than
+/// just looking cramped.
+fn render_frame(frame: &mut Frame, app: &mut App) {
+    let area = frame.area();
+    if area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT {
+        render_too_small_message(frame, area);
+        return;
+    }
+
+    match app.screen {
+        Screen::Menu => render_menu(frame, app, area),
+        Screen::Playing => render_playing_screen(frame, app, area),
+        Screen::Won => render_won(frame, app, area),
+        Screen::TimesUp => render_times_up(frame, area),
+        Screen::GameOver => render_game_over(frame, app, area),
+        Screen::Stats => render_stats(frame, app, area),
+        Screen::Replay => render_replay(frame, app, area),
+        Screen::Load => render_load(frame, app, area),
+        Screen::Library => render_library(frame, app, area),
+        Screen::Authoring => render_authoring_screen(frame, app, area),
+        Screen::Heatmap => render_heatmap(frame, app, area),
+        Screen::Settings => render_settings(frame, app, area),
+    }
+
+    if app.show_help {
+        render_help_overlay(frame, app, area);
+    }
+    if app.confirm_reset {
+        render_confirm_reset_overlay(frame, area);
+    }
+    if app.confirm_quit {
+        render_confirm_quit_overlay(frame, area);
+    }
+    if app.confirm_restart {
+        render_confirm_restart_overlay(frame, area);
+    }
+}
+
+/// Renders the header and grid, or a "too small" message in place of them
+/// if `area` can't fit the minimum playing-screen size. Keeping this
+/// separate from `render_table`'s own internal centering means a resize
+/// that shrinks the terminal below the minimum degrades to a clear message
+/// instead of a clipped or misplaced grid.
+fn render_playing_screen(f: &mut Frame, app: &mut App, area: Rect) {
+    if area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT {
+        render_too_small_message(f, area);
+        return;
+    }
+
+    let pending_hint = app.pending_hint.clone();
+    let status_message = app.status_message();
+    let constraints = if pending_hint.is_some() || status_message.is_some() {
+        vec![Constraint::Percentage(15), Constraint::Percentage(75), Constraint::Percentage(10)]
+    } else {
+        vec![Constraint::Percentage(15), Constraint::Percentage(85)]
+    };
+    let layout = Layout::default().direction(Direction::Vertical).constraints(constraints).split(area);
+
+    let border_style = app.theme.border;
+    render_header(f, app, layout[0]);
+    app.table_area = layout[1];
+    render_table(f, app, layout[1]);
+    if let Some(hint) = pending_hint {
+        render_hint_footer(f, &hint, border_style, layout[2]);
+    } else if let Some(message) = status_message {
+        render_status_footer(f, &message, border_style, layout[2]);
+    }
+}
+
+/// Renders the puzzle-authoring screen: the same grid as play, with a
+/// header explaining the controls instead of the usual timer and mistake
+/// counters, and any status message (e.g. a failed lock attempt) below.
+fn render_authoring_screen(f: &mut Frame, app: &mut App, area: Rect) {
+    if area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT {
+        render_too_small_message(f, area);
+        return;
+    }
+
+    let status_message = app.status_message();
+    let constraints = vec![Constraint::Percentage(15), Constraint::Percentage(75), Constraint::Percentage(10)];
+    let layout = Layout::default().direction(Direction::Vertical).constraints(constraints).split(area);
+
+    let border_style = app.theme.border;
+    let block = Block::bordered().border_set(border::THICK).border_style(border_style);
+    let text = "Authoring — digits place givens, Enter locks the puzzle in, Esc abandons it";
+    f.render_widget(Paragraph::new(text).centered().block(block), layout[0]);
+    app.table_area = layout[1];
+    render_table(f, app, layout[1]);
+    if let Some(message) = status_message {
+        render_status_footer(f, &message, border_style, layout[2]);
+    }
+}
+
+/// Shows a pending teaching hint's technique and explanation below the
+/// grid, asking for another `Action::Hint` press to apply it.
+fn render_hint_footer(f: &mut Frame, hint: &PendingHint, border_style: Style, area: Rect) {
+    let block = Block::bordered().border_set(border::THICK).border_style(border_style);
+    let text = format!("Hint ({}): {} — press again to apply", hint.technique, hint.explanation);
+    f.render_widget(Paragraph::new(text).centered().block(block), area);
+}
+
+/// Shows a transient status message (e.g. "Saved", "No hints left") below
+/// the grid, set via `App::set_status` and cleared once its TTL elapses.
+fn render_status_footer(f: &mut Frame, message: &str, border_style: Style, area: Rect) {
+    let block = Block::bordered().border_set(border::THICK).border_style(border_style);
+    f.render_widget(Paragraph::new(message).centered().block(block), area);
+}
+
+/// Shown instead of the header and grid when the terminal is too small to
+/// fit them, so a resize never leaves the player looking at a clipped or
+/// misaligned board.
+fn render_too_small_message(f: &mut Frame, area: Rect) {
+    let message = format!("Terminal too small.\nResize to at least {MIN_TERMINAL_WIDTH}x{MIN_TERMINAL_HEIGHT}.");
+    let rows = Layout::default()
+        .constraints([Constraint::Length(1); 2])
+        .flex(Flex::Center)
+        .split(area);
+    for (line, row) in message.lines().zip(rows.iter()) {
+        Paragraph::new(Text::from(line)).centered().render(*row, f.buffer_mut());
+    }
+}
+
+/// Renders the difficulty- and board-size-selection menu, highlighting the
+/// current picks.
+fn render_menu(f: &mut Frame, app: &mut App, area: Rect) {
+    let title = Title::from(" Welcome to Rusuku ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(area);
+    block.render(area, f.buffer_mut());
+
+    let rows = Layout::default()
+        .constraints([Constraint::Length(1); DIFFICULTIES.len() + 13])
+        .flex(Flex::Center)
+        .split(inner);
+
+    for (i, difficulty) in DIFFICULTIES.iter().enumerate() {
+        let label = Text::from(format!("{difficulty:?}"));
+        let label = if i == app.menu_selection {
+            label.reversed()
+        } else {
+            label
+        };
+        Paragraph::new(label).centered().render(rows[i], f.buffer_mut());
+    }
+
+    let size_row = rows[DIFFICULTIES.len() + 1];
+    let sizes: Vec<String> = VARIANTS
+        .iter()
+        .enumerate()
+        .map(|(i, size)| {
+            if i == app.size_selection {
+                format!("[{size}]")
+            } else {
+                format!(" {size} ")
+            }
+        })
+        .collect();
+    Paragraph::new(Text::from(sizes.join("  ")))
+        .centered()
+        .render(size_row, f.buffer_mut());
+
+    let countdown_row = rows[DIFFICULTIES.len() + 2];
+    let countdown_label = if app.countdown_mode {
+        format!("Countdown: On ({}s) [t]", DEFAULT_TIME_LIMIT.as_secs())
+    } else {
+        "Countdown: Off [t]".to_string()
+    };
+    Paragraph::new(Text::from(countdown_label).dim())
+        .centered()
+        .render(countdown_row, f.buffer_mut());
+
+    let strict_row = rows[DIFFICULTIES.len() + 3];
+    let strict_label = if app.strict_mode {
+        "Strict: On [w]"
+    } else {
+        "Strict: Off [w]"
+    };
+    Paragraph::new(Text::from(strict_label).dim())
+        .centered()
+        .render(strict_row, f.buffer_mut());
+
+    let daily_row = rows[DIFFICULTIES.len() + 4];
+    let daily_label = if app.daily_mode {
+        "Daily challenge: On [d]"
+    } else {
+        "Daily challenge: Off [d]"
+    };
+    Paragraph::new(Text::from(daily_label).dim())
+        .centered()
+        .render(daily_row, f.buffer_mut());
+
+    let lives_row = rows[DIFFICULTIES.len() + 5];
+    let lives_label = if app.lives_mode {
+        format!("Lives: On ({STARTING_LIVES}) [m]")
+    } else {
+        "Lives: Off [m]".to_string()
+    };
+    Paragraph::new(Text::from(lives_label).dim())
+        .centered()
+        .render(lives_row, f.buffer_mut());
+
+    let diagonal_row = rows[DIFFICULTIES.len() + 6];
+    let diagonal_label = if app.diagonal_mode {
+        "X-Sudoku: On [x]"
+    } else {
+        "X-Sudoku: Off [x]"
+    };
+    Paragraph::new(Text::from(diagonal_label).dim())
+        .centered()
+        .render(diagonal_row, f.buffer_mut());
+
+    let auto_notes_row = rows[DIFFICULTIES.len() + 7];
+    let auto_notes_label = if app.auto_notes {
+        "Auto notes: On [A]"
+    } else {
+        "Auto notes: Off [A]"
+    };
+    Paragraph::new(Text::from(auto_notes_label).dim())
+        .centered()
+        .render(auto_notes_row, f.buffer_mut());
+
+    let expert_rendering_row = rows[DIFFICULTIES.len() + 8];
+    let expert_rendering_label = if app.settings.expert_rendering {
+        "Expert rendering: On [E]"
+    } else {
+        "Expert rendering: Off [E]"
+    };
+    Paragraph::new(Text::from(expert_rendering_label).dim())
+        .centered()
+        .render(expert_rendering_row, f.buffer_mut());
+
+    let stats_row = rows[DIFFICULTIES.len() + 9];
+    Paragraph::new(Text::from("Stats [i]").dim())
+        .centered()
+        .render(stats_row, f.buffer_mut());
+
+    let load_row = rows[DIFFICULTIES.len() + 10];
+    Paragraph::new(Text::from("Load [L]").dim())
+        .centered()
+        .render(load_row, f.buffer_mut());
+
+    let library_row = rows[DIFFICULTIES.len() + 11];
+    Paragraph::new(Text::from("Library [b]").dim())
+        .centered()
+        .render(library_row, f.buffer_mut());
+
+    let settings_row = rows[DIFFICULTIES.len() + 12];
+    Paragraph::new(Text::from("Settings [O]").dim())
+        .centered()
+        .render(settings_row, f.buffer_mut());
+}
+
+/// Renders the stats screen, showing each difficulty's completed-game
+/// count and average completion time.
+fn render_stats(f: &mut Frame, app: &App, area: Rect) {
+    let title = Title::from(" Stats ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(area);
+    block.render(area, f.buffer_mut());
+
+    let rows = Layout::default()
+        .constraints(vec![Constraint::Length(1); DIFFICULTIES.len() + 2])
+        .flex(Flex::Center)
+        .split(inner);
+
+    let header = format!("{:<8} {:>8} {:>10}", "", "games", "avg time");
+    Paragraph::new(Text::from(header).bold())
+        .centered()
+        .render(rows[0], f.buffer_mut());
+
+    for (i, difficulty) in DIFFICULTIES.iter().enumerate() {
+        let stats = app.completion_stats.get(difficulty).copied().unwrap_or_default();
+        let avg = match stats.average_secs() {
+            Some(secs) => format!("{:02}:{:02}", secs / 60, secs % 60),
+            None => "--:--".to_string(),
+        };
+        let line = format!("{:<8?} {:>8} {:>10}", difficulty, stats.games_completed, avg);
+        Paragraph::new(Text::from(line)).centered().render(rows[i + 1], f.buffer_mut());
+    }
+
+    Paragraph::new(Text::from("any key to return").dim())
+        .centered()
+        .render(rows[DIFFICULTIES.len() + 1], f.buffer_mut());
+}
+
+/// Renders the load screen: every named save slot, with its difficulty and
+/// elapsed time, or a message if there aren't any yet.
+fn render_load(f: &mut Frame, app: &App, area: Rect) {
+    let title = Title::from(" Load ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(area);
+    block.render(area, f.buffer_mut());
+
+    if app.slots.is_empty() {
+        let rows = Layout::default().constraints([Constraint::Length(1); 1]).flex(Flex::Center).split(inner);
+        Paragraph::new(Text::from("no saved slots").dim()).centered().render(rows[0], f.buffer_mut());
+        return;
+    }
+
+    let rows = Layout::default()
+        .constraints(vec![Constraint::Length(1); app.slots.len() + 1])
+        .flex(Flex::Center)
+        .split(inner);
+
+    for (i, slot) in app.slots.iter().enumerate() {
+        let elapsed = slot.elapsed.as_secs();
+        let line = format!("{:<20} {:<8?} {:02}:{:02}", slot.name, slot.difficulty, elapsed / 60, elapsed % 60);
+        let text = Text::from(line);
+        let text = if i == app.load_selection { text.patch_style(app.theme.cursor) } else { text };
+        Paragraph::new(text).centered().render(rows[i], f.buffer_mut());
+    }
+
+    Paragraph::new(Text::from("Enter to load, Esc to return").dim())
+        .centered()
+        .render(rows[app.slots.len()], f.buffer_mut());
+}
+
+/// Renders the library screen: every bundled puzzle, with its difficulty,
+/// or a message if the library is somehow empty.
+fn render_library(f: &mut Frame, app: &App, area: Rect) {
+    let title = Title::from(" Library ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(area);
+    block.render(area, f.buffer_mut());
+
+    if app.library.is_empty() {
+        let rows = Layout::default().constraints([Constraint::Length(1); 1]).flex(Flex::Center).split(inner);
+        Paragraph::new(Text::from("no puzzles in the library").dim()).centered().render(rows[0], f.buffer_mut());
+        return;
+    }
+
+    let rows = Layout::default()
+        .constraints(vec![Constraint::Length(1); app.library.len() + 1])
+        .flex(Flex::Center)
+        .split(inner);
+
+    for (i, puzzle) in app.library.iter().enumerate() {
+        let line = format!("{:<20} {:<8?}", puzzle.name, puzzle.difficulty);
+        let text = Text::from(line);
+        let text = if i == app.library_selection { text.patch_style(app.theme.cursor) } else { text };
+        Paragraph::new(text).centered().render(rows[i], f.buffer_mut());
+    }
+
+    Paragraph::new(Text::from("Enter to load, Esc to return").dim())
+        .centered()
+        .render(rows[app.library.len()], f.buffer_mut());
+}
+
+/// Renders the settings screen: one row per preference showing its current
+/// value, with the highlighted row marked by the cursor style.
+fn render_settings(f: &mut Frame, app: &App, area: Rect) {
+    let title = Title::from(" Settings ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(area);
+    block.render(area, f.buffer_mut());
+
+    let rows = Layout::default()
+        .constraints(vec![Constraint::Length(1); SETTINGS_ROWS + 1])
+        .flex(Flex::Center)
+        .split(inner);
+
+    let labels = [
+        format!("Theme: {}", app.theme_name),
+        format!("Peer highlighting: {}", if app.highlight_peers { "On" } else { "Off" }),
+        format!("Auto notes: {}", if app.auto_notes { "On" } else { "Off" }),
+        format!("Advance on fill: {:?}", app.settings.advance_on_fill),
+        format!("Bell: {}", if app.settings.bell_enabled { "On" } else { "Off" }),
+        format!("Hide timer: {}", if app.hide_timer { "On" } else { "Off" }),
+        format!("Default difficulty: {:?}", app.settings.default_difficulty),
+    ];
+
+    for (i, label) in labels.iter().enumerate() {
+        let text = Text::from(label.as_str());
+        let text = if i == app.settings_selection { text.patch_style(app.theme.cursor) } else { text };
+        Paragraph::new(text).centered().render(rows[i], f.buffer_mut());
+    }
+
+    Paragraph::new(Text::from("Enter/Space to toggle, Esc to return").dim())
+        .centered()
+        .render(rows[SETTINGS_ROWS], f.buffer_mut());
+}
+
+/// Renders the screen shown when a countdown-mode game runs out of time
+/// before the board is solved.
+fn render_times_up(f: &mut Frame, area: Rect) {
+    let title = Title::from(" Welcome to Rusuku ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(area);
+    block.render(area, f.buffer_mut());
+
+    let rows = Layout::default()
+        .constraints([Constraint::Length(1); 1])
+        .flex(Flex::Center)
+        .split(inner);
+
+    Paragraph::new(Text::from("Time's up!".red().bold()))
+        .centered()
+        .render(rows[0], f.buffer_mut());
+}
+
+/// Renders the screen shown when a lives-mode game runs out of lives
+/// before the board is solved.
+fn render_game_over(f: &mut Frame, app: &App, area: Rect) {
+    let title = Title::from(" Welcome to Rusuku ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(area);
+    block.render(area, f.buffer_mut());
+
+    let rows = Layout::default()
+        .constraints([Constraint::Length(1); 2])
+        .flex(Flex::Center)
+        .split(inner);
+
+    Paragraph::new(Text::from("Game Over!".red().bold()))
+        .centered()
+        .render(rows[0], f.buffer_mut());
+
+    Paragraph::new(Text::from(format!("Out of lives after {} moves", app.game.moves())).dim())
+        .centered()
+        .render(rows[1], f.buffer_mut());
+}
+
+/// Renders the win screen: a banner announcing the finishing time.
+fn render_won(f: &mut Frame, app: &mut App, area: Rect) {
+    let title = Title::from(" Welcome to Rusuku ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(area);
+    block.render(area, f.buffer_mut());
+
+    let rows = Layout::default()
+        .constraints([Constraint::Length(1); 6])
+        .flex(Flex::Center)
+        .split(inner);
+
+    let minutes = app.elapsed().as_secs() / 60;
+    let seconds = app.elapsed().as_secs() % 60;
+    let message = format!("Solved in {:02}:{:02}!", minutes, seconds);
+    Paragraph::new(Text::from(message.green().bold()))
+        .centered()
+        .render(rows[0], f.buffer_mut());
+
+    let best_secs = app.best_times.get(&app.difficulty).copied().unwrap_or(0);
+    let record_line = if app.is_new_record {
+        "New best time!".to_string()
+    } else {
+        format!("Best: {:02}:{:02}", best_secs / 60, best_secs % 60)
+    };
+    Paragraph::new(Text::from(record_line.yellow()))
+        .centered()
+        .render(rows[1], f.buffer_mut());
+
+    Paragraph::new(Text::from(format!("Rating: {:?}  Givens: {}", app.puzzle_rating, app.game.board().given_count())))
+        .centered()
+        .render(rows[2], f.buffer_mut());
+
+    Paragraph::new(Text::from(format!("Score: {}", app.current_score)))
+        .centered()
+        .render(rows[3], f.buffer_mut());
+
+    let high_score_line = if app.is_new_high_score {
+        "New high score!".to_string()
+    } else {
+        let best_score = app.high_scores.get(&app.difficulty).copied().unwrap_or(0);
+        format!("High score: {best_score}")
+    };
+    Paragraph::new(Text::from(high_score_line.yellow()))
+        .centered()
+        .render(rows[4], f.buffer_mut());
+
+    Paragraph::new(Text::from("Replay [y]  Heatmap [h]  Share [w]").dim())
+        .centered()
+        .render(rows[5], f.buffer_mut());
+}
+
+/// Maps a cell's dwell time, relative to the busiest cell's, to a
+/// background color for the heatmap: a cool blue-gray for barely visited
+/// cells, ramping through to a hot red for the most-dwelled-on one.
+fn heat_style(dwell: Duration, max_dwell: Duration) -> Style {
+    let ratio = if max_dwell.is_zero() { 0.0 } else { (dwell.as_secs_f64() / max_dwell.as_secs_f64()).clamp(0.0, 1.0) };
+    let red = (30.0 + ratio * 225.0) as u8;
+    let blue = (40.0 - ratio * 40.0) as u8;
+    Style::new().bg(Color::Rgb(red, 30, blue)).fg(Color::White)
+}
+
+/// Renders the post-game dwell-time heatmap: each cell shaded by how long
+/// the cursor sat on it this game, from `app.cell_dwell`.
+fn render_heatmap(f: &mut Frame, app: &App, area: Rect) {
+    if area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT {
+        render_too_small_message(f, area);
+        return;
+    }
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+    Paragraph::new(Text::from("Cursor dwell time by cell -- any key to return"))
+        .centered()
+        .render(layout[0], f.buffer_mut());
+
+    let max_dwell = app.cell_dwell.iter().flatten().copied().max().unwrap_or(Duration::ZERO);
+    let rows = Layout::default().constraints([Constraint::Ratio(1, 9); 9]).split(layout[1]);
+    for (row, row_area) in rows.iter().enumerate() {
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(1, 9); 9])
+            .split(*row_area);
+        for (col, cell_area) in cols.iter().enumerate() {
+            let dwell = app.cell_dwell[row][col];
+            let style = heat_style(dwell, max_dwell);
+            let text = format!("{:>3}", dwell.as_secs());
+            Paragraph::new(Text::from(text)).style(style).centered().render(*cell_area, f.buffer_mut());
+        }
+    }
+}
+
+/// Renders the post-game replay screen: a step counter and instructions
+/// above the board as it looked after `app.replay_step` recorded moves.
+fn render_replay(f: &mut Frame, app: &mut App, area: Rect) {
+    if area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT {
+        render_too_small_message(f, area);
+        return;
+    }
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(15), Constraint::Percentage(85)])
+        .split(area);
+
+    let step = app.replay_step;
+    let total = app.move_history.len();
+    let elapsed = app.move_history.get(step.wrapping_sub(1)).map_or(Duration::ZERO, |(at, _)| *at);
+    let header = format!(
+        "Move {step}/{total} at {:02}:{:02} -- left/right to step, Esc to return",
+        elapsed.as_secs() / 60,
+        elapsed.as_secs() % 60,
+    );
+    Paragraph::new(Text::from(header)).centered().render(layout[0], f.buffer_mut());
+
+    let replay_board = app.replay_board();
+    let original_board = std::mem::replace(app.game.board_mut(), replay_board);
+    render_table(f, app, layout[1]);
+    *app.game.board_mut() = original_board;
+}
+
+/// Renders a centered panel listing every current keybinding and the
+/// action it triggers, read live from `app.key_bindings` so it can't drift
+/// from what's actually bound.
+fn render_help_overlay(f: &mut Frame, app: &App, area: Rect) {
+    let overlay_area = centered_rect(60, 80, area);
+    Clear.render(overlay_area, f.buffer_mut());
+
+    let title = Title::from(" Keybindings ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(overlay_area);
+    block.render(overlay_area, f.buffer_mut());
+
+    let bindings = app.key_bindings.bindings();
+    let rows = Layout::default()
+        .constraints(vec![Constraint::Length(1); bindings.len() + 1])
+        .split(inner);
+
+    for (i, (key, action)) in bindings.iter().enumerate() {
+        let line = format!("{:<8} {:?}", key_label(*key), action);
+        Paragraph::new(Text::from(line)).render(rows[i], f.buffer_mut());
+    }
+
+    Paragraph::new(Text::from("? or Esc to close").dim())
+        .centered()
+        .render(rows[bindings.len()], f.buffer_mut());
+}
+
+/// Renders the "erase all my entries" confirmation prompt shown before a
+/// reset, since it can't be undone.
+fn render_confirm_reset_overlay(f: &mut Frame, area: Rect) {
+    let overlay_area = centered_rect(40, 20, area);
+    Clear.render(overlay_area, f.buffer_mut());
+
+    let title = Title::from(" Reset puzzle? ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(overlay_area);
+    block.render(overlay_area, f.buffer_mut());
+
+    let rows = Layout::default()
+        .constraints([Constraint::Length(1); 2])
+        .flex(Flex::Center)
+        .split(inner);
+    Paragraph::new(Text::from("This clears every entry you've made."))
+        .centered()
+        .render(rows[0], f.buffer_mut());
+    Paragraph::new(Text::from("y/Enter to confirm, any other key to cancel").dim())
+        .centered()
+        .render(rows[1], f.buffer_mut());
+}
+
+/// Renders the "quit without saving" confirmation prompt shown before
+/// exiting a game with unfinished, unsolved entries.
+fn render_confirm_quit_overlay(f: &mut Frame, area: Rect) {
+    let overlay_area = centered_rect(40, 20, area);
+    Clear.render(overlay_area, f.buffer_mut());
+
+    let title = Title::from(" Quit without saving? ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(overlay_area);
+    block.render(overlay_area, f.buffer_mut());
+
+    let rows = Layout::default()
+        .constraints([Constraint::Length(1); 2])
+        .flex(Flex::Center)
+        .split(inner);
+    Paragraph::new(Text::from("The current puzzle hasn't been saved."))
+        .centered()
+        .render(rows[0], f.buffer_mut());
+    Paragraph::new(Text::from("y/Enter to quit, any other key to cancel").dim())
+        .centered()
+        .render(rows[1], f.buffer_mut());
+}
+
+/// Renders the "restart from scratch" confirmation prompt shown before
+/// clearing every entry and resetting the clock, since it can't be undone.
+fn render_confirm_restart_overlay(f: &mut Frame, area: Rect) {
+    let overlay_area = centered_rect(40, 20, area);
+    Clear.render(overlay_area, f.buffer_mut());
+
+    let title = Title::from(" Restart puzzle? ".bold());
+    let block = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .border_set(border::THICK);
+    let inner = block.inner(overlay_area);
+    block.render(overlay_area, f.buffer_mut());
+
+    let rows = Layout::default()
+        .constraints([Constraint::Length(1); 2])
+        .flex(Flex::Center)
+        .split(inner);
+    Paragraph::new(Text::from("This clears every entry and resets your time."))
+        .centered()
+        .render(rows[0], f.buffer_mut());
+    Paragraph::new(Text::from("y/Enter to confirm, any other key to cancel").dim())
+        .centered()
+        .render(rows[1], f.buffer_mut());
+}
+
+/// A short, human-readable label for a bound key, e.g. `q` or `Esc`.
+fn key_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(c) => c.to_string(),
+        other => format!("{other:?}"),
+    }
+}
+
+/// Returns the `percent_x` by `percent_y` rect centered within `area`.
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(percent_y)])
+        .flex(Flex::Center)
+        .split(area);
+    let horizontal = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(percent_x)])
+        .flex(Flex::Center)
+        .split(vertical[0]);
+    horizontal[0]
+}
+
+/// Formats a duration as `MM:SS`, switching to `H:MM:SS` once it passes an
+/// hour so the minutes field never has to grow past two digits.
+fn format_elapsed(d: Duration) -> String {
+    let total_secs = d.as_secs();
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+fn render_header(f: &mut Frame, app: &mut App, area: Rect) {
+    let title = Title::from(" Welcome to Rusuku ".bold());
+
+    let header_layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1); 3])
+        .split(area);
+
+    let (displayed_time, low_on_time) = match app.remaining() {
+        Some(remaining) => (remaining, remaining < LOW_TIME_WARNING),
+        None => (app.elapsed(), false),
+    };
+    let elapsed_time =
+        if app.hide_timer { "--:--".to_string() } else { format_elapsed(displayed_time) };
+    let timer_style = if low_on_time { app.theme.wrong } else { app.theme.timer };
+    let elapsed_time = Text::styled(elapsed_time, timer_style);
+
+    let top_middle = Block::bordered()
+        .title(title.alignment(Alignment::Center))
+        .borders(Borders::TOP | Borders::BOTTOM)
+        .border_set(border::THICK)
+        .border_style(app.theme.border);
+
+    f.render_widget(
+        Paragraph::new(elapsed_time).centered().block(top_middle),
+        header_layout[1],
+    );
+
+    let moves_label = if app.lives_mode {
+        format!("Moves: {}  Lives: {}  {}", app.game.moves(), app.lives_remaining, progress_label(app))
+    } else {
+        format!("Moves: {}  {}", app.game.moves(), progress_label(app))
+    };
+    let moves = Block::default()
+        .borders(Borders::ALL)
+        .border_set(border::THICK)
+        .border_style(app.theme.border);
+    f.render_widget(
+        Paragraph::new(moves_label).centered().block(moves),
+        header_layout[0],
+    );
+
+    let remaining_digits = Block::default()
+        .borders(Borders::ALL)
+        .border_set(border::THICK)
+        .border_style(app.theme.border);
+    f.render_widget(
+        Paragraph::new(remaining_digits_line(app))
+            .centered()
+            .block(remaining_digits),
+        header_layout[2],
+    );
+}
+
+/// A "62/81" readout of how many of the board's cells are filled in so
+/// far (givens included), out of its total cell count.
+fn progress_label(app: &App) -> String {
+    let side = app.game.board().size().side();
+    format!("{}/{}", app.game.board().filled_count(), side * side)
+}
+
+/// A "1:3 2:5 …" line showing how many of each digit are still unplaced,
+/// with digits that are already fully placed grayed out.
+fn remaining_digits_line(app: &App) -> Line<'static> {
+    let side = app.game.board().size().side() as u8;
+    let counts = app.game.board().digit_counts();
+
+    let mut spans = Vec::new();
+    for digit in 1..=side {
+        if digit > 1 {
+            spans.push(Span::raw(" "));
+        }
+        let remaining = side - counts[digit as usize - 1];
+        let label = format!("{digit}:{remaining}");
+        spans.push(if remaining == 0 {
+            label.dark_gray()
+        } else {
+            Span::raw(label)
+        });
+    }
+    Line::from(spans)
+}
+
+/// Renders pencil-mark candidates as a compact grid of digits shaped like
+/// the board's own boxes, dimmed so they read as secondary to real entries.
+fn notes_text(notes: &std::collections::HashSet<u8>, size: BoardSize) -> Text<'static> {
+    let (box_rows, box_cols) = size.box_dims();
+    let lines: Vec<String> = (0..box_rows)
+        .map(|row| {
+            (1..=box_cols)
+                .map(|col| {
+                    let digit = (row * box_cols + col) as u8;
+                    if notes.contains(&digit) {
+                        digit_to_char(digit).to_string()
+                    } else {
+                        " ".to_string()
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    Text::from(lines.join("\n")).dim()
+}
+
+/// The background a player's annotation color renders as. Fixed across
+/// themes, since these are colors the player explicitly chose to tell
+/// cells apart, not part of the overall look.
+fn annotation_background(color: AnnotationColor) -> Color {
+    match color {
+        AnnotationColor::Red => Color::Red,
+        AnnotationColor::Orange => Color::Rgb(0xff, 0x8c, 0x00),
+        AnnotationColor::Yellow => Color::Yellow,
+        AnnotationColor::Green => Color::Green,
+        AnnotationColor::Blue => Color::Blue,
+        AnnotationColor::Purple => Color::Rgb(0x80, 0x00, 0x80),
+        AnnotationColor::Cyan => Color::Cyan,
+        AnnotationColor::Magenta => Color::Magenta,
+        AnnotationColor::Gray => Color::Gray,
+    }
+}
+
+/// The other cells that share a row, column, or box with `(row, col)` on a
+/// board of `size`, not including `(row, col)` itself.
+fn unit_cells(row: usize, col: usize, size: BoardSize) -> impl Iterator<Item = (usize, usize)> {
+    let side = size.side();
+    let (box_rows, box_cols) = size.box_dims();
+    let box_row = (row / box_rows) * box_rows;
+    let box_col = (col / box_cols) * box_cols;
+
+    (0..side)
+        .map(move |i| (row, i))
+        .chain((0..side).map(move |i| (i, col)))
+        .chain((box_row..box_row + box_rows).flat_map(move |r| (box_col..box_col + box_cols).map(move |c| (r, c))))
+        .filter(move |&cell| cell != (row, col))
+        .collect::<std::collections::HashSet<_>>()
+        .into_iter()
+}
+
+/// The row, column, and box cell groups containing `(row, col)` on a board
+/// of `size`, in that order — the three units a single placement can
+/// complete.
+fn units_at(row: usize, col: usize, size: BoardSize) -> [Vec<(usize, usize)>; 3] {
+    let side = size.side();
+    let (box_rows, box_cols) = size.box_dims();
+    let box_row = (row / box_rows) * box_rows;
+    let box_col = (col / box_cols) * box_cols;
+
+    let unit_row = (0..side).map(|c| (row, c)).collect();
+    let unit_col = (0..side).map(|r| (r, col)).collect();
+    let unit_box = (box_row..box_row + box_rows)
+        .flat_map(|r| (box_col..box_col + box_cols).map(move |c| (r, c)))
+        .collect();
+    [unit_row, unit_col, unit_box]
+}
+
+/// Whether every cell in `cells` holds a distinct digit, i.e. the unit is
+/// both complete and free of conflicts.
+fn unit_is_complete_and_correct(board: &Board, cells: &[(usize, usize)]) -> bool {
+    let mut seen = std::collections::HashSet::new();
+    cells.iter().all(|&(row, col)| board.get(row, col).digit().is_some_and(|digit| seen.insert(digit)))
+}
+
+/// The terminal width of a box holding `box_cols` cells side by side,
+/// matching the classic 9x9 board's original 18-cell-wide boxes when
+/// `box_cols` is 3.
+fn box_width(box_cols: usize) -> u16 {
+    (6 * box_cols) as u16
+}
+
+/// The terminal height of a box holding `box_rows` cells stacked
+/// vertically, matching the classic 9x9 board's original 18-cell-tall
+/// boxes when `box_rows` is 3.
+fn box_height(box_rows: usize) -> u16 {
+    (6 * box_rows) as u16
+}
+
+/// The borders drawn around the box at `(vi, hi)` in a grid of `n_cols` by
+/// `n_rows` boxes. Every box draws its own bottom and right edge (serving
+/// as either an internal divider or, for the last box in that axis, the
+/// outer edge); only the first box in each axis also draws the opposite
+/// outer edge, so shared dividers are never drawn twice.
+fn box_borders(vi: usize, hi: usize) -> Borders {
+    let mut borders = Borders::BOTTOM | Borders::RIGHT;
+    if vi == 0 {
+        borders |= Borders::LEFT;
+    }
+    if hi == 0 {
+        borders |= Borders::TOP;
+    }
+    borders
+}
+
+/// The line-drawing glyph where two grid lines cross at box-boundary
+/// `(vb, hb)`, out of `n_cols` by `n_rows` boxes. `vb`/`hb` range over
+/// `0..=n_cols`/`0..=n_rows`: the endpoints are the grid's outer edges, and
+/// everything in between is an internal divider.
+fn junction_symbol(vb: usize, hb: usize, n_cols: usize, n_rows: usize) -> &'static str {
+    let (up, down) = (hb > 0, hb < n_rows);
+    let (left, right) = (vb > 0, vb < n_cols);
+    match (up, down, left, right) {
+        (false, true, false, true) => symbols::line::THICK_TOP_LEFT,
+        (false, true, true, false) => symbols::line::THICK_TOP_RIGHT,
+        (true, false, false, true) => symbols::line::THICK_BOTTOM_LEFT,
+        (true, false, true, false) => symbols::line::THICK_BOTTOM_RIGHT,
+        (false, true, true, true) => symbols::line::THICK_HORIZONTAL_DOWN,
+        (true, false, true, true) => symbols::line::THICK_HORIZONTAL_UP,
+        (true, true, false, true) => symbols::line::THICK_VERTICAL_RIGHT,
+        (true, true, true, false) => symbols::line::THICK_VERTICAL_LEFT,
+        _ => symbols::line::THICK_CROSS,
+    }
+}
+
+/// The border glyphs for the box at `(vi, hi)`, joined seamlessly with its
+/// neighbors via `junction_symbol` at each of its four corners.
+fn box_border_set(vi: usize, hi: usize, n_cols: usize, n_rows: usize) -> symbols::border::Set {
+    symbols::border::Set {
+        top_left: junction_symbol(vi, hi, n_cols, n_rows),
+        top_right: junction_symbol(vi + 1, hi, n_cols, n_rows),
+        bottom_left: junction_symbol(vi, hi + 1, n_cols, n_rows),
+        bottom_right: junction_symbol(vi + 1, hi + 1, n_cols, n_rows),
+        ..symbols::border::THICK
+    }
+}
+
+fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let size = app.game.board().size();
+    let (box_rows, box_cols) = size.box_dims();
+    let side = size.side();
+    let n_cols = side / box_cols;
+    let n_rows = side / box_rows;
+
+    let conflicts = if app.settings.expert_rendering {
+        std::collections::HashSet::new()
+    } else {
+        app.game.board().conflicts()
+    };
+    let peers: std::collections::HashSet<(usize, usize)> = if app.highlight_peers && !app.settings.expert_rendering {
+        unit_cells(app.game.cursor().0, app.game.cursor().1, size).collect()
+    } else {
+        std::collections::HashSet::new()
+    };
+    let selected_digit = if app.settings.expert_rendering {
+        None
+    } else {
+        app.game.board().get(app.game.cursor().0, app.game.cursor().1).digit()
+    };
+    let flashing_cell = app.flashing_cell();
+    let flashing_units = app.flashing_units();
+    let coaching_cells = if app.coaching_mode {
+        app.coaching_cells()
+    } else {
+        std::collections::HashSet::new()
+    };
+    let hint_cells: std::collections::HashSet<(usize, usize)> =
+        app.pending_hint.as_ref().map(|hint| hint.cells.clone()).unwrap_or_default();
+    let unfocused_cells: std::collections::HashSet<(usize, usize)> = if let Some(digit) = app.digit_focus {
+        (0..side)
+            .flat_map(|row| (0..side).map(move |col| (row, col)))
+            .filter(|&(row, col)| {
+                let cell = app.game.board().get(row, col);
+                let relevant =
+                    cell.digit() == Some(digit) || (cell == Cell::Empty && app.game.board().candidates(row, col).contains(&digit));
+                !relevant
+            })
+            .collect()
+    } else {
+        std::collections::HashSet::new()
+    };
+
+    let vertical_layout = Layout::default()
+        .constraints(vec![Constraint::Max(box_width(box_cols)); n_cols])
+        .direction(Direction::Horizontal)
+        .flex(Flex::Center)
+        .split(area);
+
+    for (vi, vl) in vertical_layout.iter().enumerate() {
+        let horizontal_layout = Layout::default()
+            .constraints(vec![Constraint::Max(box_height(box_rows)); n_rows])
+            .direction(Direction::Vertical)
+            .flex(Flex::Center)
+            .split(*vl);
+
+        for (hi, hl) in horizontal_layout.iter().enumerate() {
+            let border_set = box_border_set(vi, hi, n_cols, n_rows);
+            let block = Block::default().borders(box_borders(vi, hi)).border_set(border_set);
+            let inner = block.inner(*hl);
+            block.render(*hl, f.buffer_mut());
+
+            let cell_rows = Layout::default()
+                .constraints(vec![Constraint::Ratio(1, box_rows as u32); box_rows])
+                .direction(Direction::Vertical)
+                .split(inner);
+
+            for (r, row_area) in cell_rows.iter().enumerate() {
+                let cell_cols = Layout::default()
+                    .constraints(vec![Constraint::Ratio(1, box_cols as u32); box_cols])
+                    .direction(Direction::Horizontal)
+                    .split(*row_area);
+
+                for (c, cell_area) in cell_cols.iter().enumerate() {
+                    let row = hi * box_rows + r;
+                    let col = vi * box_cols + c;
+                    let cell = app.game.board().get(row, col);
+
+                    let notes = app.game.board().notes(row, col);
+                    let peeked_digit = (app.peek_solution && cell == Cell::Empty)
+                        .then(|| app.game.solution().and_then(|solution| solution.get(row, col).digit()))
+                        .flatten();
+                    let text = match (cell, peeked_digit) {
+                        (Cell::Empty, Some(d)) => Text::styled(digit_to_char(d).to_string(), app.theme.dimmed),
+                        (Cell::Empty, None) if !notes.is_empty() => notes_text(notes, size),
+                        (Cell::Empty, None) => Text::from(" "),
+                        (Cell::Given(d), _) => Text::styled(digit_to_char(d).to_string(), app.theme.given),
+                        (Cell::Filled(d), _) => Text::styled(digit_to_char(d).to_string(), app.theme.filled),
+                    };
+                    let text = if app.settings.high_contrast_givens && matches!(cell, Cell::Given(_)) {
+                        text.patch_style(Style::new().add_modifier(Modifier::UNDERLINED))
+                    } else {
+                        text
+                    };
+                    let text = if app.settings.box_shading && (vi + hi).is_multiple_of(2) {
+                        text.patch_style(app.theme.box_shade)
+                    } else {
+                        text
+                    };
+                    let text = if let Some(color) = app.game.board().annotation(row, col) {
+                        text.patch_style(Style::new().bg(annotation_background(color)))
+                    } else {
+                        text
+                    };
+                    let on_diagonal = app.game.board().is_diagonal() && (row == col || row + col == side - 1);
+                    let text = if on_diagonal {
+                        text.patch_style(app.theme.diagonal)
+                    } else {
+                        text
+                    };
+                    let text = if coaching_cells.contains(&(row, col)) {
+                        text.patch_style(app.theme.coaching_highlight)
+                    } else {
+                        text
+                    };
+                    let text = if hint_cells.contains(&(row, col)) {
+                        text.patch_style(app.theme.hint_highlight)
+                    } else {
+                        text
+                    };
+                    let text = if unfocused_cells.contains(&(row, col)) {
+                        text.patch_style(app.theme.dimmed)
+                    } else {
+                        text
+                    };
+                    let text = if app.game.board().is_wrong(row, col) {
+                        text.patch_style(app.theme.wrong)
+                    } else {
+                        text
+                    };
+                    let text = if conflicts.contains(&(row, col)) {
+                        text.patch_style(app.theme.conflict)
+                    } else {
+                        text
+                    };
+                    let text = if peers.contains(&(row, col)) {
+                        text.patch_style(app.theme.peer_highlight)
+                    } else {
+                        text
+                    };
+                    let text = if (row, col) != app.game.cursor() && selected_digit.is_some() && cell.digit() == selected_digit {
+                        text.patch_style(app.theme.selected_digit_highlight)
+                    } else {
+                        text
+                    };
+                    let text = if (row, col) == app.game.cursor() && app.cursor_blink_on() {
+                        text.patch_style(app.theme.cursor)
+                    } else {
+                        text
+                    };
+                    let text = if flashing_units.contains(&(row, col)) {
+                        text.patch_style(app.theme.unit_complete)
+                    } else {
+                        text
+                    };
+                    let text = if flashing_cell == Some((row, col)) {
+                        text.patch_style(app.theme.wrong)
+                    } else {
+                        text
+                    };
+
+                    Paragraph::new(text)
+                        .centered()
+                        .render(*cell_area, f.buffer_mut());
+                }
+            }
+        }
+    }
+}
+
+impl Widget for &App {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = Title::from(" Welcome to Rusuku ".bold());
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(15), Constraint::Percentage(85)])
+            .split(area);
+
+        let header_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Fill(1); 3])
+            .split(layout[0]);
+
+        let elapsed_time = format_elapsed(self.elapsed());
+        let elapsed_time = Text::from(elapsed_time.yellow().bold());
+
+        let top_middle = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .borders(Borders::TOP | Borders::BOTTOM)
+            .border_set(border::THICK);
+
+        Paragraph::new(elapsed_time)
+            .centered()
+            .block(top_middle)
+            .render(header_layout[1], buf);
+
+        Block::default()
+            .borders(Borders::ALL)
+            .border_set(border::THICK)
+            .render(header_layout[0], buf);
+
+        Block::default()
+            .borders(Borders::ALL)
+            .border_set(border::THICK)
+            .render(header_layout[2], buf);
+
+        Block::bordered()
+            .border_set(border::THICK)
+            .render(layout[1], buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use ratatui::crossterm::event::KeyEventState;
+    use ratatui::style::Style;
+    use std::cell::Cell as StdCell;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// A `Clock` that only moves forward when `advance` is called, so
+    /// timer tests can assert exact durations instead of tolerating
+    /// wall-clock jitter. Cloning shares the same underlying time, so a
+    /// clone can be handed to `App` while the test keeps one to drive it.
+    #[derive(Debug, Clone)]
+    struct MockClock(Rc<StdCell<Instant>>);
+
+    impl MockClock {
+        fn new() -> Self {
+            Self(Rc::new(StdCell::new(Instant::now())))
+        }
+
+        fn advance(&self, duration: Duration) {
+            self.0.set(self.0.get() + duration);
+        }
+    }
+
+    impl clock::Clock for MockClock {
+        fn now(&self) -> Instant {
+            self.0.get()
+        }
+    }
+
+    /// A `Feedback` sink that counts bell requests instead of ringing them,
+    /// so tests can assert on how many were made without any actual noise.
+    /// Cloning shares the same counter, mirroring `MockClock`.
+    #[derive(Debug, Clone)]
+    struct MockFeedback(Rc<StdCell<u32>>);
+
+    impl MockFeedback {
+        fn new() -> Self {
+            Self(Rc::new(StdCell::new(0)))
+        }
+
+        fn bell_count(&self) -> u32 {
+            self.0.get()
+        }
+    }
+
+    impl feedback::Feedback for MockFeedback {
+        fn bell(&self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    /// A `Restore` that counts attempts instead of touching the real
+    /// terminal, mirroring `MockFeedback`.
+    #[derive(Debug, Clone)]
+    struct MockRestore(Rc<StdCell<u32>>);
+
+    impl MockRestore {
+        fn new() -> Self {
+            Self(Rc::new(StdCell::new(0)))
+        }
+
+        fn call_count(&self) -> u32 {
+            self.0.get()
+        }
+    }
+
+    impl Restore for MockRestore {
+        fn restore(&self) -> io::Result<()> {
+            self.0.set(self.0.get() + 1);
+            Ok(())
+        }
+    }
+
+    /// A `Clipboard` that returns a fixed, settable string instead of
+    /// reading the real system clipboard, mirroring `MockFeedback`.
+    #[derive(Debug, Clone)]
+    struct MockClipboard(Rc<RefCell<Option<String>>>);
+
+    impl MockClipboard {
+        fn new(contents: Option<&str>) -> Self {
+            Self(Rc::new(RefCell::new(contents.map(String::from))))
+        }
+    }
+
+    impl clipboard::Clipboard for MockClipboard {
+        fn paste(&self) -> Option<String> {
+            self.0.borrow().clone()
+        }
+    }
+
+    /// A backend that always fails to draw, wrapping a real `TestBackend`
+    /// for every other method, to exercise `App::draw`'s recovery path
+    /// without a real terminal.
+    struct FailingBackend(ratatui::backend::TestBackend);
+
+    impl ratatui::backend::Backend for FailingBackend {
+        fn draw<'a, I>(&mut self, _content: I) -> io::Result<()>
+        where
+            I: Iterator<Item = (u16, u16, &'a ratatui::buffer::Cell)>,
+        {
+            Err(io::Error::other("simulated draw failure"))
+        }
+
+        fn hide_cursor(&mut self) -> io::Result<()> {
+            self.0.hide_cursor()
+        }
+
+        fn show_cursor(&mut self) -> io::Result<()> {
+            self.0.show_cursor()
+        }
+
+        fn get_cursor_position(&mut self) -> io::Result<ratatui::layout::Position> {
+            self.0.get_cursor_position()
+        }
+
+        fn set_cursor_position<P: Into<ratatui::layout::Position>>(&mut self, position: P) -> io::Result<()> {
+            self.0.set_cursor_position(position)
+        }
+
+        fn clear(&mut self) -> io::Result<()> {
+            self.0.clear()
+        }
+
+        fn size(&self) -> io::Result<ratatui::layout::Size> {
+            self.0.size()
+        }
+
+        fn window_size(&mut self) -> io::Result<ratatui::backend::WindowSize> {
+            self.0.window_size()
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.0.flush()
+        }
+    }
+
+    #[test]
+    fn render() {
+        let app = App::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 55, 18));
+
+        app.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec![
+            "┏━━━━━━━━━━━━━━━━┓ Welcome to Rusuku ┏━━━━━━━━━━━━━━━━┓",
+            "┃                ┃       00:00       ┃                ┃",
+            "┗━━━━━━━━━━━━━━━━┛━━━━━━━━━━━━━━━━━━━┗━━━━━━━━━━━━━━━━┛",
+            "┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┃                                                     ┃",
+            "┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛",
+        ]);
+        let title_style = Style::new().bold();
+        let timer_style = Style::new().yellow().bold();
         expected.set_style(Rect::new(18, 0, 19, 1), title_style);
         expected.set_style(Rect::new(25, 1, 5, 1), timer_style);
 
-        // note ratatui also has an assert_buffer_eq! macro that can be used to
-        // compare buffers and display the differences in a more readable way
-        assert_eq!(buf, expected);
+        // note ratatui also has an assert_buffer_eq! macro that can be used to
+        // compare buffers and display the differences in a more readable way
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn a_failed_draw_attempts_restore_and_surfaces_the_error_instead_of_panicking() {
+        let restore = MockRestore::new();
+        let mut app = App {
+            terminal_restore: Box::new(restore.clone()),
+            ..App::default()
+        };
+        let mut terminal = Terminal::new(FailingBackend(ratatui::backend::TestBackend::new(20, 10))).unwrap();
+
+        let result = app.draw(&mut terminal);
+
+        assert!(result.is_err());
+        assert_eq!(restore.call_count(), 1);
+    }
+
+    #[test]
+    fn format_elapsed_uses_mm_ss_under_an_hour_and_h_mm_ss_past_it() {
+        assert_eq!(format_elapsed(Duration::from_secs(0)), "00:00");
+        assert_eq!(format_elapsed(Duration::from_secs(59)), "00:59");
+        assert_eq!(format_elapsed(Duration::from_secs(90)), "01:30");
+        assert_eq!(format_elapsed(Duration::from_secs(3661)), "1:01:01");
+    }
+
+    #[test]
+    fn render_header_shows_remaining_counts_and_grays_out_exhausted_digits() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::default();
+        for row in 0..9 {
+            app.game.board_mut().set(row, 0, Cell::Given(1));
+        }
+        app.game.board_mut().set(0, 1, Cell::Given(2));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(60, 3)).unwrap();
+        terminal
+            .draw(|frame| render_header(frame, &mut app, Rect::new(0, 0, 60, 3)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("1:0"));
+        assert!(text.contains("2:8"));
+
+        let one_cell = buf
+            .content()
+            .iter()
+            .find(|cell| cell.symbol() == "1" && cell.style().fg == Some(ratatui::style::Color::DarkGray));
+        assert!(one_cell.is_some());
+    }
+
+    #[test]
+    fn render_header_shows_how_many_cells_are_filled_out_of_the_total() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::default();
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+        app.game.board_mut().set(1, 1, Cell::Filled(3));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(60, 3)).unwrap();
+        terminal
+            .draw(|frame| render_header(frame, &mut app, Rect::new(0, 0, 60, 3)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("2/81"));
+    }
+
+    #[test]
+    fn hiding_the_timer_shows_a_placeholder_while_elapsed_time_keeps_advancing() {
+        use ratatui::backend::TestBackend;
+
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            hide_timer: true,
+            is_timer_running: true,
+            start_time: Some(clock.now()),
+            ..App::default()
+        };
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(60, 3)).unwrap();
+        terminal
+            .draw(|frame| render_header(frame, &mut app, Rect::new(0, 0, 60, 3)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("--:--"));
+        assert!(!text.contains("00:00"));
+
+        clock.advance(Duration::from_secs(5));
+
+        assert_eq!(app.elapsed(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn box_shading_alternates_backgrounds_and_leaves_the_grid_lines_intact() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App {
+            settings: Settings { bell_enabled: true, box_shading: true, expert_rendering: false, advance_on_fill: AdvanceOnFill::Off, high_contrast_givens: false, default_difficulty: Difficulty::default() },
+            ..App::default()
+        };
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(54, 54)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+
+        // (0, 0) is in the top-left box (shaded); (0, 3) is in the
+        // top-middle box (unshaded), one cell to the right.
+        assert_eq!(buf[(3, 1)].style().bg, Some(app.theme.box_shade.bg.unwrap()));
+        assert_eq!(buf[(21, 1)].style().bg, Some(ratatui::style::Color::Reset));
+
+        // The border between the boxes is still drawn, unaffected by shading.
+        assert!(!buf[(17, 1)].symbol().trim().is_empty());
+    }
+
+    #[test]
+    fn render_table_draws_givens_and_filled_digits() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::default();
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+        app.game.board_mut().set(8, 8, Cell::Filled(3));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(54, 54)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+
+        let given = &buf[(3, 1)];
+        assert_eq!(given.symbol(), "5");
+        assert_eq!(
+            given.style(),
+            Style::new()
+                .white()
+                .bold()
+                .reversed()
+                .bg(ratatui::style::Color::Reset)
+        );
+
+        let filled = &buf[(50, 47)];
+        assert_eq!(filled.symbol(), "3");
+        assert_eq!(
+            filled.style(),
+            Style::new().cyan().bg(ratatui::style::Color::Reset)
+        );
+    }
+
+    #[test]
+    fn high_contrast_givens_underlines_only_given_cells() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App {
+            settings: Settings {
+                high_contrast_givens: true,
+                ..Settings::default()
+            },
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+        app.game.board_mut().set(8, 8, Cell::Filled(3));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(54, 54)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+
+        let given = &buf[(3, 1)];
+        assert!(given.style().add_modifier.contains(Modifier::UNDERLINED));
+
+        let filled = &buf[(50, 47)];
+        assert!(!filled.style().add_modifier.contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn render_playing_screen_shows_a_fallback_message_when_the_terminal_is_too_small() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::default();
+        app.game.board_mut().set(0, 0, Cell::Given(9));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(30, 10)).unwrap();
+        terminal
+            .draw(|frame| render_playing_screen(frame, &mut app, Rect::new(0, 0, 30, 10)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("too small"));
+        assert!(!text.contains('9'));
+    }
+
+    #[test]
+    fn render_frame_shows_a_resize_prompt_instead_of_panicking_on_a_tiny_terminal() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::default();
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(10, 5)).unwrap();
+        terminal.draw(|frame| render_frame(frame, &mut app)).unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("Terminal"));
+        assert!(text.contains("Resize"));
+    }
+
+    #[test]
+    fn render_table_uses_the_active_themes_cursor_style() {
+        use ratatui::backend::TestBackend;
+
+        let high_contrast = theme::Theme::named(theme::ThemeName::HighContrast);
+        let mut app = App {
+            theme: high_contrast,
+            ..App::default()
+        };
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(54, 54)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+        let cursor_cell = &buf[(3, 1)];
+        assert_eq!(cursor_cell.style().fg, high_contrast.cursor.fg);
+        assert_eq!(cursor_cell.style().bg, high_contrast.cursor.bg);
+    }
+
+    #[test]
+    fn the_cursor_blinks_off_and_on_roughly_every_500ms_while_running() {
+        use ratatui::backend::TestBackend;
+
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            ..App::default()
+        };
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(54, 54)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+        let first_style = terminal.backend().buffer().clone()[(3, 1)].style();
+
+        clock.advance(CURSOR_BLINK_INTERVAL);
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+        let second_style = terminal.backend().buffer().clone()[(3, 1)].style();
+
+        assert_ne!(first_style, second_style);
+    }
+
+    #[test]
+    fn render_table_highlights_every_cell_sharing_the_selected_digit() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::default();
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+        app.game.board_mut().set(1, 1, Cell::Filled(5));
+        app.game.board_mut().set(8, 8, Cell::Filled(3));
+        app.game.set_cursor((0, 0));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(54, 54)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+
+        let other_five = &buf[(9, 6)];
+        assert_eq!(other_five.symbol(), "5");
+        assert!(other_five.style().bg == Some(ratatui::style::Color::Blue));
+
+        let unrelated = &buf[(50, 47)];
+        assert_eq!(unrelated.symbol(), "3");
+        assert_ne!(unrelated.style().bg, Some(ratatui::style::Color::Blue));
+    }
+
+    #[test]
+    fn annotated_cells_render_with_their_tagged_background_colors() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::default();
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+        app.game.board_mut().set(8, 8, Cell::Given(9));
+        app.game.board_mut().set_annotation(0, 0, Some(AnnotationColor::Red));
+        app.game.board_mut().set_annotation(8, 8, Some(AnnotationColor::Blue));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(54, 54)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+        let first = &buf[(3, 1)];
+        assert_eq!(first.symbol(), "5");
+        assert_eq!(first.style().bg, Some(annotation_background(AnnotationColor::Red)));
+
+        let last = &buf[(50, 47)];
+        assert_eq!(last.symbol(), "9");
+        assert_eq!(last.style().bg, Some(annotation_background(AnnotationColor::Blue)));
+    }
+
+    #[test]
+    fn set_cell_annotation_tags_the_selected_cell_and_zero_clears_it() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('m').into());
+        assert!(app.annotate_mode);
+        app.handle_key_event(KeyCode::Char('1').into());
+        assert!(!app.annotate_mode);
+        assert_eq!(app.game.board().annotation(0, 0), Some(AnnotationColor::Red));
+
+        app.handle_key_event(KeyCode::Char('m').into());
+        app.handle_key_event(KeyCode::Char('0').into());
+        assert_eq!(app.game.board().annotation(0, 0), None);
+    }
+
+    #[test]
+    fn expert_rendering_suppresses_conflict_highlighting() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App {
+            settings: Settings { bell_enabled: true, box_shading: false, expert_rendering: true, advance_on_fill: AdvanceOnFill::Off, high_contrast_givens: false, default_difficulty: Difficulty::default() },
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+        app.game.board_mut().set(0, 1, Cell::Filled(5));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(54, 54)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+        assert!(buf.content().iter().any(|cell| cell.symbol() == "5"));
+        assert!(!buf.content().iter().any(|cell| cell.style().bg == Some(ratatui::style::Color::Red)));
+    }
+
+    #[test]
+    fn unit_cells_covers_the_row_column_and_box_of_a_center_selection() {
+        let peers: std::collections::HashSet<_> = unit_cells(4, 4, BoardSize::Classic9).collect();
+
+        assert_eq!(peers.len(), 20);
+        assert!(!peers.contains(&(4, 4)));
+        for i in (0..9).filter(|&i| i != 4) {
+            assert!(peers.contains(&(4, i)));
+            assert!(peers.contains(&(i, 4)));
+        }
+        for row in 3..6 {
+            for col in 3..6 {
+                assert!(peers.contains(&(row, col)) || (row, col) == (4, 4));
+            }
+        }
+    }
+
+    #[test]
+    fn cell_at_maps_pixel_coordinates_to_the_containing_cell() {
+        let app = App {
+            table_area: Rect::new(0, 0, 54, 54),
+            ..App::default()
+        };
+
+        assert_eq!(app.cell_at(4, 1), Some((0, 0)));
+        assert_eq!(app.cell_at(50, 47), Some((8, 8)));
+        assert_eq!(app.cell_at(36, 1), Some((0, 6)));
+    }
+
+    #[test]
+    fn cell_at_ignores_clicks_on_the_borders_between_boxes() {
+        let app = App {
+            table_area: Rect::new(0, 0, 54, 54),
+            ..App::default()
+        };
+
+        // x=17 is the shared border between the first and middle box; x=35
+        // is the shared border between the middle and last box.
+        assert_eq!(app.cell_at(17, 1), None);
+        assert_eq!(app.cell_at(35, 1), None);
+    }
+
+    #[test]
+    fn cell_at_ignores_clicks_outside_the_grid() {
+        let app = App {
+            table_area: Rect::new(0, 0, 54, 54),
+            ..App::default()
+        };
+
+        assert_eq!(app.cell_at(0, 0), None);
+        assert_eq!(app.cell_at(54, 1), None);
+        assert_eq!(app.cell_at(1, 54), None);
+    }
+
+    #[test]
+    fn clicking_moves_the_cursor_once_the_game_has_started() {
+        let mut app = App {
+            table_area: Rect::new(0, 0, 54, 54),
+            ..App::default()
+        };
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        app.handle_click(50, 47);
+        assert_eq!(app.game.cursor(), (8, 8));
+
+        // Clicks on a box border don't move the cursor anywhere.
+        app.handle_click(17, 1);
+        assert_eq!(app.game.cursor(), (8, 8));
+    }
+
+    #[test]
+    fn clicking_is_ignored_before_the_game_starts() {
+        let mut app = App {
+            table_area: Rect::new(0, 0, 54, 54),
+            ..App::default()
+        };
+
+        app.handle_click(4, 1);
+        assert_eq!(app.game.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn handle_key_event() -> io::Result<()> {
+        let mut app = App::default();
+        app.handle_key_event(KeyCode::Char('q').into());
+        assert!(app.exit);
+
+        Ok(())
+    }
+
+    #[test]
+    fn a_remapped_quit_key_triggers_quit_instead_of_the_default() {
+        use std::fs;
+
+        let dir = std::env::temp_dir().join(format!("rusuku-app-config-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("keybindings.toml");
+        fs::write(&path, "Quit = \"x\"\n").unwrap();
+
+        let mut app = App {
+            key_bindings: KeyBindings::load(&path),
+            screen: Screen::Playing,
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('q').into());
+        assert!(!app.exit);
+
+        app.handle_key_event(KeyCode::Char('x').into());
+        assert!(app.exit);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn cursor_clamps_at_top_left() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+        app.handle_key_event(KeyCode::Up.into());
+        app.handle_key_event(KeyCode::Char('h').into());
+        assert_eq!(app.game.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn cursor_clamps_at_bottom_right() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+        app.game.set_cursor((8, 8));
+        app.handle_key_event(KeyCode::Down.into());
+        app.handle_key_event(KeyCode::Char('l').into());
+        assert_eq!(app.game.cursor(), (8, 8));
+    }
+
+    #[test]
+    fn cursor_movement_ignored_before_game_starts() {
+        let mut app = App::default();
+        app.handle_key_event(KeyCode::Down.into());
+        app.handle_key_event(KeyCode::Char('l').into());
+        assert_eq!(app.game.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn a_count_prefix_multiplies_the_next_movement() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        app.handle_key_event(KeyCode::Char('3').into());
+        app.handle_key_event(KeyCode::Char('l').into());
+
+        assert_eq!(app.game.cursor(), (0, 3));
+    }
+
+    #[test]
+    fn a_count_prefix_clamps_at_the_grid_edge() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        app.handle_key_event(KeyCode::Char('9').into());
+        app.handle_key_event(KeyCode::Char('j').into());
+
+        assert_eq!(app.game.cursor(), (8, 0));
+    }
+
+    #[test]
+    fn a_count_prefix_is_dropped_by_a_non_movement_key() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        app.handle_key_event(KeyCode::Char('3').into());
+        app.handle_key_event(KeyCode::Char('p').into());
+        app.continue_timer();
+        app.handle_key_event(KeyCode::Char('l').into());
+
+        assert_eq!(app.game.cursor(), (0, 1));
+    }
+
+    #[test]
+    fn go_to_box_jumps_the_cursor_to_the_boxs_top_left_cell() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        app.handle_key_event(KeyCode::Char('G').into());
+        app.handle_key_event(KeyCode::Char('5').into());
+        assert_eq!(app.game.cursor(), (3, 3));
+
+        app.handle_key_event(KeyCode::Char('G').into());
+        app.handle_key_event(KeyCode::Char('9').into());
+        assert_eq!(app.game.cursor(), (6, 6));
+    }
+
+    #[test]
+    fn go_to_box_mode_is_dropped_by_a_non_digit_key() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        app.handle_key_event(KeyCode::Char('G').into());
+        app.handle_key_event(KeyCode::Char('l').into());
+        assert_eq!(app.game.cursor(), (0, 1));
+
+        // The `5` now fills the cursor's cell rather than jumping, since
+        // go-to-box mode was dropped by the intervening movement.
+        app.handle_key_event(KeyCode::Char('5').into());
+        assert_eq!(app.game.cursor(), (0, 1));
+    }
+
+    #[test]
+    fn digit_focus_toggles_on_and_off_without_filling_the_cell() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+        app.game.board_mut().set(0, 0, Cell::Empty);
+
+        app.handle_key_event(KeyCode::Char('F').into());
+        app.handle_key_event(KeyCode::Char('7').into());
+        assert_eq!(app.digit_focus, Some(7));
+        assert_eq!(app.game.board().get(0, 0), Cell::Empty);
+
+        app.handle_key_event(KeyCode::Char('F').into());
+        app.handle_key_event(KeyCode::Char('7').into());
+        assert_eq!(app.digit_focus, None);
+    }
+
+    #[test]
+    fn digit_focus_mode_is_dropped_by_a_non_digit_key() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+        app.game.board_mut().set(0, 1, Cell::Empty);
+
+        app.handle_key_event(KeyCode::Char('F').into());
+        app.handle_key_event(KeyCode::Char('l').into());
+        assert_eq!(app.game.cursor(), (0, 1));
+
+        // The `7` now fills the cursor's cell rather than setting focus,
+        // since digit focus mode was dropped by the intervening movement.
+        app.handle_key_event(KeyCode::Char('7').into());
+        assert_eq!(app.digit_focus, None);
+        assert_eq!(app.game.board().get(0, 1), Cell::Filled(7));
+    }
+
+    #[test]
+    fn render_table_dims_cells_that_cant_hold_the_focused_digit() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::default();
+        app.game.board_mut().set(0, 0, Cell::Given(7));
+        app.game.board_mut().set(8, 8, Cell::Filled(3));
+        app.digit_focus = Some(7);
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(54, 54)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+
+        let focused = &buf[(3, 1)];
+        assert_eq!(focused.symbol(), "7");
+        assert_ne!(focused.style(), app.theme.dimmed);
+
+        let dimmed = &buf[(50, 47)];
+        assert_eq!(dimmed.symbol(), "3");
+        assert_eq!(dimmed.style().add_modifier, app.theme.dimmed.add_modifier);
+    }
+
+    #[test]
+    fn peeking_the_solution_dims_empty_cells_with_their_answer_and_clears_when_toggled_off() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::default();
+        app.game.board_mut().set(0, 0, Cell::Empty);
+        app.game.set_cursor((8, 8));
+        let mut solution = Board::default();
+        solution.set(0, 0, Cell::Given(5));
+        app.game.set_solution(Some(solution));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(54, 54)).unwrap();
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(3, 1)].symbol(), " ");
+
+        app.peek_solution = true;
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+        let buf = terminal.backend().buffer().clone();
+        assert_eq!(buf[(3, 1)].symbol(), "5");
+        assert_eq!(buf[(3, 1)].style().add_modifier, app.theme.dimmed.add_modifier);
+
+        app.peek_solution = false;
+        terminal
+            .draw(|frame| render_table(frame, &mut app, Rect::new(0, 0, 54, 54)))
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(3, 1)].symbol(), " ");
+    }
+
+    #[test]
+    fn losing_focus_pauses_the_timer_and_excludes_the_gap() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            ..App::default()
+        };
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        clock.advance(Duration::from_secs(20));
+        app.pause_for_focus_loss();
+        assert!(!app.is_timer_running);
+        assert_eq!(app.elapsed(), Duration::from_secs(20));
+
+        clock.advance(Duration::from_secs(50));
+        assert_eq!(app.elapsed(), Duration::from_secs(20));
+
+        app.resume_from_focus_gain();
+        assert!(app.is_timer_running);
+        assert_eq!(app.elapsed(), Duration::from_secs(20));
+    }
+
+    #[test]
+    fn an_arrow_key_after_a_manual_pause_resumes_the_timer_and_still_moves() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        app.handle_key_event(KeyCode::Char('p').into());
+        assert!(!app.is_timer_running);
+
+        app.handle_key_event(KeyCode::Down.into());
+
+        assert!(app.is_timer_running);
+        assert_eq!(app.game.cursor(), (1, 0));
+    }
+
+    #[test]
+    fn a_key_press_does_not_resume_a_pause_caused_by_losing_focus() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        app.pause_for_focus_loss();
+        assert!(!app.is_timer_running);
+
+        app.handle_key_event(KeyCode::Down.into());
+
+        assert!(!app.is_timer_running);
+        assert_eq!(app.game.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn a_countdown_game_transitions_to_times_up_once_the_limit_elapses() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            countdown_mode: true,
+            ..App::default()
+        };
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+        assert_eq!(app.remaining(), Some(DEFAULT_TIME_LIMIT));
+
+        clock.advance(DEFAULT_TIME_LIMIT - Duration::from_secs(1));
+        app.check_time_up();
+        assert_eq!(app.screen, Screen::Playing);
+
+        clock.advance(Duration::from_secs(2));
+        app.check_time_up();
+        assert_eq!(app.screen, Screen::TimesUp);
+        assert!(!app.is_timer_running);
+    }
+
+    #[test]
+    fn a_manual_pause_is_not_resumed_by_regaining_focus() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        app.stop_timer();
+        app.resume_from_focus_gain();
+
+        assert!(!app.is_timer_running);
+    }
+
+    #[test]
+    fn elapsed_tracks_the_mock_clock_exactly_across_pause_and_resume() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            ..App::default()
+        };
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        clock.advance(Duration::from_secs(30));
+        app.stop_timer();
+        assert_eq!(app.elapsed(), Duration::from_secs(30));
+
+        // Time passing while paused shouldn't count.
+        clock.advance(Duration::from_secs(1000));
+        assert_eq!(app.elapsed(), Duration::from_secs(30));
+
+        app.continue_timer();
+        clock.advance(Duration::from_secs(15));
+        assert_eq!(app.elapsed(), Duration::from_secs(45));
+    }
+
+    #[test]
+    fn a_huge_clock_jump_while_running_is_capped_rather_than_exploding() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            ..App::default()
+        };
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+
+        clock.advance(Duration::from_secs(60 * 60 * 24 * 365));
+        assert_eq!(app.elapsed(), MAX_ELAPSED_DELTA);
+
+        app.stop_timer();
+        assert_eq!(app.elapsed(), MAX_ELAPSED_DELTA);
+    }
+
+    #[test]
+    fn toggling_a_setting_updates_it_in_memory_and_saves_it_to_disk() {
+        use std::fs;
+
+        let dir = std::env::temp_dir().join(format!("rusuku-settings-screen-test-{}", std::process::id()));
+        let settings_path = dir.join("settings.toml");
+        let mut app = App {
+            screen: Screen::Settings,
+            settings_path: settings_path.clone(),
+            settings_selection: 4,
+            ..App::default()
+        };
+        assert!(app.settings.bell_enabled);
+
+        app.handle_key_event(KeyCode::Enter.into());
+
+        assert!(!app.settings.bell_enabled);
+        assert!(!Settings::load(&settings_path).bell_enabled);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn toggling_the_theme_updates_it_in_memory_and_saves_it_to_disk() {
+        use std::fs;
+
+        let dir = std::env::temp_dir().join(format!("rusuku-settings-theme-test-{}", std::process::id()));
+        let theme_path = dir.join("theme.toml");
+        let mut app = App {
+            screen: Screen::Settings,
+            theme_path: theme_path.clone(),
+            settings_selection: 0,
+            ..App::default()
+        };
+        assert_eq!(app.theme_name, ThemeName::Default);
+
+        app.handle_key_event(KeyCode::Enter.into());
+
+        assert_eq!(app.theme_name, ThemeName::HighContrast);
+        assert_eq!(theme::load_name(&theme_path), ThemeName::HighContrast);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn tick_elapsed_is_true_only_once_tick_rate_has_passed() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            tick_rate: Duration::from_millis(250),
+            ..App::default()
+        };
+
+        // No tick has happened yet, so the first check always ticks.
+        assert!(app.tick_elapsed());
+        // Immediately after, no time has passed.
+        assert!(!app.tick_elapsed());
+
+        clock.advance(Duration::from_millis(100));
+        assert!(!app.tick_elapsed());
+
+        clock.advance(Duration::from_millis(150));
+        assert!(app.tick_elapsed());
+        assert!(!app.tick_elapsed());
+    }
+
+    #[test]
+    fn poll_timeout_grows_while_idle_and_resets_on_input() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            max_idle_poll: Duration::from_millis(250),
+            ..App::default()
+        };
+
+        // Freshly started, nothing's idle yet.
+        assert_eq!(app.poll_timeout(), MIN_POLL_INTERVAL);
+
+        app.idle_since = Some(clock.now());
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(app.poll_timeout(), MIN_POLL_INTERVAL + Duration::from_millis(100));
+
+        // Keeps growing, but never past `max_idle_poll`.
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(app.poll_timeout(), Duration::from_millis(250));
+
+        // An event drops it straight back down.
+        app.idle_since = None;
+        assert_eq!(app.poll_timeout(), MIN_POLL_INTERVAL);
+    }
+
+    #[test]
+    fn cursor_dwell_time_accumulates_on_the_selected_cell_per_tick() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            tick_rate: Duration::from_millis(250),
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+
+        app.game.set_cursor((0, 0));
+        assert!(app.tick_elapsed());
+        app.record_dwell(app.tick_rate);
+
+        clock.advance(Duration::from_millis(250));
+        assert!(app.tick_elapsed());
+        app.record_dwell(app.tick_rate);
+
+        app.game.set_cursor((5, 5));
+        clock.advance(Duration::from_millis(250));
+        assert!(app.tick_elapsed());
+        app.record_dwell(app.tick_rate);
+
+        assert_eq!(app.cell_dwell[0][0], Duration::from_millis(500));
+        assert_eq!(app.cell_dwell[5][5], Duration::from_millis(250));
+
+        let hot = heat_style(app.cell_dwell[0][0], app.cell_dwell[0][0]);
+        let cold = heat_style(app.cell_dwell[5][5], app.cell_dwell[0][0]);
+        assert_ne!(hot, cold);
+    }
+
+    #[test]
+    fn winning_freezes_the_elapsed_time() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            is_timer_running: true,
+            start_time: Some(clock.now()),
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        for (row, values) in SOLVED_GRID.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                app.game.board_mut().set(row, col, Cell::Given(value));
+            }
+        }
+        app.game.board_mut().set(8, 8, Cell::Empty);
+        app.game.set_cursor((8, 8));
+
+        clock.advance(Duration::from_secs(42));
+        app.handle_key_event(KeyCode::Char('9').into());
+
+        assert_eq!(app.screen, Screen::Won);
+        let frozen = app.elapsed();
+        assert_eq!(frozen, Duration::from_secs(42));
+
+        // Time passing after the win, and a redundant stop_timer call,
+        // shouldn't move the displayed time.
+        clock.advance(Duration::from_secs(1000));
+        assert_eq!(app.elapsed(), frozen);
+        app.stop_timer();
+        assert_eq!(app.elapsed(), frozen);
+    }
+
+    #[test]
+    fn fill_cell_does_not_overwrite_a_given() {
+        let mut app = App::default();
+        app.start_game(Difficulty::Medium, BoardSize::Classic9);
+        let (row, col) = (0..9)
+            .flat_map(|row| (0..9).map(move |col| (row, col)))
+            .find(|&(row, col)| matches!(app.game.board().get(row, col), Cell::Given(_)))
+            .expect("a freshly generated puzzle has at least one given");
+        app.game.set_cursor((row, col));
+        let given = app.game.board().get(row, col);
+
+        app.handle_key_event(KeyCode::Char('7').into());
+        assert_eq!(app.game.board().get(row, col), given);
+    }
+
+    #[test]
+    fn a_rejected_overwrite_flashes_the_given_until_the_duration_elapses() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+        app.game.set_cursor((0, 0));
+
+        app.handle_key_event(KeyCode::Char('7').into());
+        assert_eq!(app.flashing_cell(), Some((0, 0)));
+
+        clock.advance(FLASH_DURATION);
+        assert_eq!(app.flashing_cell(), None);
+    }
+
+    #[test]
+    fn fill_cell_places_a_digit_and_zero_clears_it() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.handle_key_event(KeyCode::Char('7').into());
+        assert_eq!(app.game.board().get(0, 0), Cell::Filled(7));
+
+        app.handle_key_event(KeyCode::Char('0').into());
+        assert_eq!(app.game.board().get(0, 0), Cell::Empty);
+    }
+
+    #[test]
+    fn advance_on_fill_off_leaves_the_cursor_on_the_filled_cell() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('7').into());
+
+        assert_eq!(app.game.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn advance_on_fill_next_cell_moves_to_the_next_cell_regardless_of_its_contents() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            settings: Settings {
+                advance_on_fill: AdvanceOnFill::NextCell,
+                ..Settings::default()
+            },
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 1, Cell::Given(2));
+
+        app.handle_key_event(KeyCode::Char('7').into());
+
+        assert_eq!(app.game.cursor(), (0, 1));
+    }
+
+    #[test]
+    fn advance_on_fill_next_cell_wraps_from_the_last_cell_back_to_the_first() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            game: Game { cursor: (8, 8), ..Game::default() },
+            settings: Settings {
+                advance_on_fill: AdvanceOnFill::NextCell,
+                ..Settings::default()
+            },
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('7').into());
+
+        assert_eq!(app.game.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn advance_on_fill_next_empty_skips_over_already_filled_cells() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            settings: Settings {
+                advance_on_fill: AdvanceOnFill::NextEmpty,
+                ..Settings::default()
+            },
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 1, Cell::Given(2));
+        app.game.board_mut().set(0, 2, Cell::Given(3));
+
+        app.handle_key_event(KeyCode::Char('7').into());
+
+        assert_eq!(app.game.cursor(), (0, 3));
+    }
+
+    #[test]
+    fn advance_on_fill_next_empty_wraps_past_the_last_cell_back_to_the_first_empty_one() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            game: Game { cursor: (8, 8), ..Game::default() },
+            settings: Settings {
+                advance_on_fill: AdvanceOnFill::NextEmpty,
+                ..Settings::default()
+            },
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('7').into());
+
+        assert_eq!(app.game.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn auto_notes_removes_a_placed_digit_from_peers_and_restores_it_once_cleared_and_legal() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            auto_notes: true,
+            ..App::default()
+        };
+        app.game.board_mut().toggle_note(0, 1, 7);
+        app.game.board_mut().toggle_note(0, 1, 3);
+
+        app.handle_key_event(KeyCode::Char('7').into());
+        assert_eq!(app.game.board().get(0, 0), Cell::Filled(7));
+        assert!(!app.game.board().notes(0, 1).contains(&7));
+        assert!(app.game.board().notes(0, 1).contains(&3));
+
+        app.handle_key_event(KeyCode::Char('0').into());
+        assert!(app.game.board().notes(0, 1).contains(&7));
+    }
+
+    #[test]
+    fn auto_notes_off_leaves_peers_pencil_marks_untouched() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.board_mut().toggle_note(0, 1, 7);
+
+        app.handle_key_event(KeyCode::Char('7').into());
+
+        assert!(app.game.board().notes(0, 1).contains(&7));
+    }
+
+    #[test]
+    fn a_numpad_digit_key_fills_the_selected_cell_the_same_as_a_top_row_digit() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        let numpad_five = KeyEvent::new_with_kind_and_state(
+            KeyCode::Char('5'),
+            KeyModifiers::NONE,
+            KeyEventKind::Press,
+            KeyEventState::KEYPAD,
+        );
+
+        app.handle_key_event(numpad_five);
+
+        assert_eq!(app.game.board().get(0, 0), Cell::Filled(5));
+    }
+
+    #[test]
+    fn moves_counts_placements_and_clears_but_not_notes() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.handle_key_event(KeyCode::Char('7').into());
+        app.move_cursor(0, 1);
+        app.handle_key_event(KeyCode::Char('8').into());
+        app.move_cursor(0, 1);
+        app.handle_key_event(KeyCode::Char('9').into());
+        assert_eq!(app.game.moves(), 3);
+
+        app.handle_key_event(KeyCode::Char('0').into());
+        assert_eq!(app.game.moves(), 4);
+
+        app.handle_key_event(KeyCode::Char('n').into());
+        app.handle_key_event(KeyCode::Char('1').into());
+        assert_eq!(app.game.moves(), 4);
+    }
+
+    #[test]
+    fn notes_mode_toggles_candidates_instead_of_filling() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.handle_key_event(KeyCode::Char('n').into());
+        app.handle_key_event(KeyCode::Char('4').into());
+        assert_eq!(app.game.board().get(0, 0), Cell::Empty);
+        assert!(app.game.board().notes(0, 0).contains(&4));
+
+        app.handle_key_event(KeyCode::Char('4').into());
+        assert!(!app.game.board().notes(0, 0).contains(&4));
+    }
+
+    #[test]
+    fn filling_a_cell_clears_its_notes() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.handle_key_event(KeyCode::Char('n').into());
+        app.handle_key_event(KeyCode::Char('4').into());
+        app.handle_key_event(KeyCode::Char('n').into());
+        app.handle_key_event(KeyCode::Char('7').into());
+
+        assert_eq!(app.game.board().get(0, 0), Cell::Filled(7));
+        assert!(app.game.board().notes(0, 0).is_empty());
+    }
+
+    #[test]
+    fn undo_and_redo_step_through_edit_history() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+
+        app.game.set_cursor((0, 0));
+        app.handle_key_event(KeyCode::Char('1').into());
+        app.game.set_cursor((0, 1));
+        app.handle_key_event(KeyCode::Char('2').into());
+        app.game.set_cursor((0, 2));
+        app.handle_key_event(KeyCode::Char('3').into());
+
+        app.handle_key_event(KeyCode::Char('u').into());
+        app.handle_key_event(KeyCode::Char('u').into());
+
+        // Matches the state right after the first fill: only (0,0) is set.
+        assert_eq!(app.game.board().get(0, 0), Cell::Filled(1));
+        assert_eq!(app.game.board().get(0, 1), Cell::Empty);
+        assert_eq!(app.game.board().get(0, 2), Cell::Empty);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert_eq!(app.game.board().get(0, 1), Cell::Filled(2));
+        assert_eq!(app.game.board().get(0, 2), Cell::Empty);
+    }
+
+    #[test]
+    fn undo_all_reverts_to_the_givens_and_leaves_redo_available() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+
+        app.game.set_cursor((0, 1));
+        app.handle_key_event(KeyCode::Char('1').into());
+        app.game.set_cursor((0, 2));
+        app.handle_key_event(KeyCode::Char('2').into());
+        app.game.set_cursor((0, 3));
+        app.handle_key_event(KeyCode::Char('3').into());
+
+        app.handle_key_event(KeyCode::Char('U').into());
+
+        assert_eq!(app.game.board().get(0, 0), Cell::Given(5));
+        assert_eq!(app.game.board().get(0, 1), Cell::Empty);
+        assert_eq!(app.game.board().get(0, 2), Cell::Empty);
+        assert_eq!(app.game.board().get(0, 3), Cell::Empty);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+
+        assert_eq!(app.game.board().get(0, 1), Cell::Filled(1));
+        assert_eq!(app.game.board().get(0, 2), Cell::Filled(2));
+        assert_eq!(app.game.board().get(0, 3), Cell::Filled(3));
+    }
+
+    #[test]
+    fn reset_to_givens_requires_confirmation_and_clears_only_filled_cells() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+
+        app.game.set_cursor((0, 1));
+        app.handle_key_event(KeyCode::Char('1').into());
+        app.game.set_cursor((0, 2));
+        app.handle_key_event(KeyCode::Char('2').into());
+        assert_eq!(app.game.moves(), 2);
+
+        app.handle_key_event(KeyCode::Char('R').into());
+        assert!(app.confirm_reset);
+        // A stray key while the prompt is open cancels instead of resetting.
+        app.handle_key_event(KeyCode::Esc.into());
+        assert!(!app.confirm_reset);
+        assert_eq!(app.game.board().get(0, 1), Cell::Filled(1));
+
+        app.handle_key_event(KeyCode::Char('R').into());
+        app.handle_key_event(KeyCode::Char('y').into());
+
+        assert_eq!(app.game.board().get(0, 0), Cell::Given(5));
+        assert_eq!(app.game.board().get(0, 1), Cell::Empty);
+        assert_eq!(app.game.board().get(0, 2), Cell::Empty);
+        assert_eq!(app.game.moves(), 0);
+        assert!(app.is_timer_running);
+    }
+
+    #[test]
+    fn restarting_clears_entries_and_the_clock_but_keeps_the_timer_running() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            is_timer_running: true,
+            screen: Screen::Playing,
+            start_time: Some(clock.now()),
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+
+        app.game.set_cursor((0, 1));
+        app.handle_key_event(KeyCode::Char('1').into());
+        clock.advance(Duration::from_secs(90));
+        app.hints_used = 3;
+        app.game.mistakes = 2;
+
+        app.handle_key_event(KeyCode::Char('T').into());
+        assert!(app.confirm_restart);
+        // A stray key while the prompt is open cancels instead of restarting.
+        app.handle_key_event(KeyCode::Esc.into());
+        assert!(!app.confirm_restart);
+        assert_eq!(app.game.board().get(0, 1), Cell::Filled(1));
+
+        app.handle_key_event(KeyCode::Char('T').into());
+        app.handle_key_event(KeyCode::Char('y').into());
+
+        assert_eq!(app.game.board().get(0, 0), Cell::Given(5));
+        assert_eq!(app.game.board().get(0, 1), Cell::Empty);
+        assert_eq!(app.hints_used, 0);
+        assert_eq!(app.game.mistakes(), 0);
+        assert!(app.is_timer_running);
+        assert!(app.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn result_summary_formats_difficulty_time_hints_and_mistakes() {
+        let clock = MockClock::new();
+        let app = App {
+            clock: Box::new(clock.clone()),
+            difficulty: Difficulty::Hard,
+            hints_used: 0,
+            is_timer_running: true,
+            start_time: Some(clock.now()),
+            ..App::default()
+        };
+        clock.advance(Duration::from_secs(7 * 60 + 43));
+
+        assert_eq!(app.result_summary(), "Rusuku Hard 07:43 ✅ 0 hints, 0 mistakes");
+    }
+
+    #[test]
+    fn quitting_a_partially_filled_board_requires_confirmation() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+        app.game.set_cursor((0, 1));
+        app.handle_key_event(KeyCode::Char('1').into());
+
+        app.handle_key_event(KeyCode::Char('q').into());
+        assert!(app.confirm_quit);
+        assert!(!app.exit);
+
+        // A stray key while the prompt is open cancels instead of quitting.
+        app.handle_key_event(KeyCode::Char('n').into());
+        assert!(!app.confirm_quit);
+        assert!(!app.exit);
+
+        app.handle_key_event(KeyCode::Char('q').into());
+        app.handle_key_event(KeyCode::Char('y').into());
+        assert!(app.exit);
+    }
+
+    #[test]
+    fn ctrl_c_exits_immediately_without_a_confirmation_prompt_even_mid_game() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+        app.game.set_cursor((0, 1));
+        app.handle_key_event(KeyCode::Char('1').into());
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+        assert!(app.exit);
+        assert!(!app.confirm_quit);
+    }
+
+    #[test]
+    fn ctrl_c_is_recognized_even_while_a_confirmation_prompt_is_open() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            confirm_reset: true,
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+        assert!(app.exit);
+    }
+
+    #[test]
+    fn quitting_a_fresh_or_solved_board_exits_immediately() {
+        let mut fresh = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        fresh.handle_key_event(KeyCode::Char('q').into());
+        assert!(fresh.exit);
+        assert!(!fresh.confirm_quit);
+
+        let mut solved = one_move_from_winning();
+        solved.handle_key_event(KeyCode::Char('9').into());
+        assert!(solved.game.is_won());
+        solved.handle_key_event(KeyCode::Char('q').into());
+        assert!(solved.exit);
+        assert!(!solved.confirm_quit);
+    }
+
+    #[test]
+    fn app_starts_on_the_menu_screen() {
+        let app = App::default();
+        assert_eq!(app.screen, Screen::Menu);
+    }
+
+    #[test]
+    fn navigating_to_hard_and_confirming_starts_a_hard_puzzle() {
+        let mut app = App::default();
+        app.handle_key_event(KeyCode::Down.into());
+        app.handle_key_event(KeyCode::Down.into());
+        assert_eq!(app.menu_selection, 2);
+
+        app.handle_key_event(KeyCode::Enter.into());
+
+        assert_eq!(app.screen, Screen::Playing);
+        let givens = (0..9)
+            .flat_map(|row| (0..9).map(move |col| (row, col)))
+            .filter(|&(row, col)| matches!(app.game.board().get(row, col), Cell::Given(_)))
+            .count();
+        // The greedy removal pass stops as soon as it hits the target, but
+        // can't always reach it if the remaining cells resist removal
+        // without breaking uniqueness, so it may fall a little short.
+        assert!(givens >= Difficulty::Hard.givens(BoardSize::Classic9));
+        assert!(givens <= Difficulty::Hard.givens(BoardSize::Classic9) + 5);
+    }
+
+    #[test]
+    fn menu_selection_does_not_go_past_the_last_difficulty() {
+        let mut app = App::default();
+        for _ in 0..10 {
+            app.handle_key_event(KeyCode::Down.into());
+        }
+        assert_eq!(app.menu_selection, DIFFICULTIES.len() - 1);
+    }
+
+    #[test]
+    fn attract_mode_fills_correct_cells_over_time_and_a_keypress_cancels_it() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            screen: Screen::Menu,
+            ..App::default()
+        };
+
+        app.advance_attract_mode();
+        assert!(!app.attract_mode);
+        clock.advance(ATTRACT_IDLE_DELAY);
+        app.advance_attract_mode();
+        assert!(app.attract_mode);
+        assert_eq!(app.screen, Screen::Playing);
+
+        let solution = app.game.solution().cloned().expect("attract mode always solves its demo puzzle");
+        let mut filled = 0;
+        for _ in 0..5 {
+            clock.advance(ATTRACT_MOVE_INTERVAL);
+            app.advance_attract_mode();
+        }
+        for row in 0..9 {
+            for col in 0..9 {
+                if let Cell::Filled(digit) = app.game.board().get(row, col) {
+                    assert_eq!(Some(digit), solution.get(row, col).digit());
+                    filled += 1;
+                }
+            }
+        }
+        assert!(filled > 0);
+
+        app.handle_key_event(KeyCode::Char('x').into());
+        assert!(!app.attract_mode);
+        assert_eq!(app.screen, Screen::Menu);
+    }
+
+    #[test]
+    fn help_overlay_toggle_pauses_the_timer_and_renders_the_bindings_list() {
+        use ratatui::backend::TestBackend;
+
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('?').into());
+        assert!(app.show_help);
+        assert!(!app.is_timer_running);
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(60, 40)).unwrap();
+        terminal
+            .draw(|frame| render_help_overlay(frame, &app, Rect::new(0, 0, 60, 40)))
+            .unwrap();
+
+        let buf = terminal.backend().buffer().clone();
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("Quit"));
+
+        app.handle_key_event(KeyCode::Esc.into());
+        assert!(!app.show_help);
+        assert!(app.is_timer_running);
+    }
+
+    #[test]
+    fn help_overlay_swallows_other_keys_while_open() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.toggle_help();
+
+        app.handle_key_event(KeyCode::Down.into());
+
+        assert_eq!(app.game.cursor(), (0, 0));
+        assert!(app.show_help);
+    }
+
+    #[test]
+    fn size_selection_wraps_neither_direction_and_starts_the_chosen_variant() {
+        let mut app = App::default();
+        app.handle_key_event(KeyCode::Left.into());
+        assert_eq!(app.size_selection, 0);
+
+        for _ in 0..VARIANTS.len() - 1 {
+            app.handle_key_event(KeyCode::Right.into());
+        }
+        assert_eq!(app.size_selection, VARIANTS.len() - 1);
+
+        app.handle_key_event(KeyCode::Right.into());
+        assert_eq!(app.size_selection, VARIANTS.len() - 1);
+
+        app.handle_key_event(KeyCode::Enter.into());
+
+        assert_eq!(app.screen, Screen::Playing);
+        assert_eq!(app.game.board().size(), VARIANTS[VARIANTS.len() - 1]);
+    }
+
+    #[test]
+    fn toggling_countdown_mode_on_the_menu_sets_the_new_games_time_limit() {
+        let mut app = App::default();
+        app.handle_key_event(KeyCode::Char('t').into());
+        assert!(app.countdown_mode);
+
+        app.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(app.time_limit, Some(DEFAULT_TIME_LIMIT));
+    }
+
+    #[test]
+    fn strict_mode_reverts_a_wrong_entry_and_flashes_the_cell() {
+        let mut solution = Board::default();
+        solution.set(0, 0, Cell::Given(5));
+        let mut app = App {
+            strict_mode: true,
+            game: Game {
+                solution: Some(solution),
+                ..Game::default()
+            },
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.set_cursor((0, 0));
+
+        app.handle_key_event(KeyCode::Char('7').into());
+
+        assert_eq!(app.game.board().get(0, 0), Cell::Empty);
+        assert_eq!(app.flashing_cell(), Some((0, 0)));
+    }
+
+    const SOLVED_GRID: [[u8; 9]; 9] = [
+        [5, 3, 4, 6, 7, 8, 9, 1, 2],
+        [6, 7, 2, 1, 9, 5, 3, 4, 8],
+        [1, 9, 8, 3, 4, 2, 5, 6, 7],
+        [8, 5, 9, 7, 6, 1, 4, 2, 3],
+        [4, 2, 6, 8, 5, 3, 7, 9, 1],
+        [7, 1, 3, 9, 2, 4, 8, 5, 6],
+        [9, 6, 1, 5, 3, 7, 2, 8, 4],
+        [2, 8, 7, 4, 1, 9, 6, 3, 5],
+        [3, 4, 5, 2, 8, 6, 1, 7, 9],
+    ];
+
+    /// A valid solved board with only the last cell left empty, so a single
+    /// digit key press completes and wins the game.
+    fn one_move_from_winning() -> App {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        for (row, values) in SOLVED_GRID.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                app.game.board_mut().set(row, col, Cell::Given(value));
+            }
+        }
+        app.game.board_mut().set(8, 8, Cell::Empty);
+        app.game.set_cursor((8, 8));
+        app
+    }
+
+    #[test]
+    fn filling_the_last_cell_of_a_valid_board_wins_the_game() {
+        let mut app = one_move_from_winning();
+
+        app.handle_key_event(KeyCode::Char('9').into());
+
+        assert!(app.game.is_won());
+        assert_eq!(app.screen, Screen::Won);
+        assert!(!app.is_timer_running);
+    }
+
+    #[test]
+    fn winning_for_the_first_time_sets_a_new_record() {
+        let mut app = one_move_from_winning();
+        app.elapsed_time = Duration::from_secs(300);
+
+        app.handle_key_event(KeyCode::Char('9').into());
+
+        assert!(app.is_new_record);
+        assert_eq!(app.best_times[&Difficulty::Medium], 300);
+    }
+
+    #[test]
+    fn winning_slower_than_the_record_does_not_overwrite_it() {
+        let mut app = one_move_from_winning();
+        app.elapsed_time = Duration::from_secs(500);
+        app.best_times.insert(Difficulty::Medium, 200);
+
+        app.handle_key_event(KeyCode::Char('9').into());
+
+        assert!(!app.is_new_record);
+        assert_eq!(app.best_times[&Difficulty::Medium], 200);
+    }
+
+    #[test]
+    fn winning_computes_and_records_a_new_high_score() {
+        let mut app = one_move_from_winning();
+        app.elapsed_time = Duration::from_secs(300);
+
+        app.handle_key_event(KeyCode::Char('9').into());
+
+        let expected = score::compute(Duration::from_secs(300), 0, 0, 0, Difficulty::Medium);
+        assert_eq!(app.current_score, expected);
+        assert!(app.is_new_high_score);
+        assert_eq!(app.high_scores[&Difficulty::Medium], expected);
+    }
+
+    #[test]
+    fn winning_with_a_lower_score_does_not_overwrite_the_high_score() {
+        let mut app = one_move_from_winning();
+        app.elapsed_time = Duration::from_secs(300);
+        app.high_scores.insert(Difficulty::Medium, u32::MAX);
+
+        app.handle_key_event(KeyCode::Char('9').into());
+
+        assert!(!app.is_new_high_score);
+        assert_eq!(app.high_scores[&Difficulty::Medium], u32::MAX);
+    }
+
+    #[test]
+    fn winning_records_a_completion_with_its_elapsed_time() {
+        let mut app = one_move_from_winning();
+        app.elapsed_time = Duration::from_secs(300);
+
+        app.handle_key_event(KeyCode::Char('9').into());
+
+        let medium = app.completion_stats[&Difficulty::Medium];
+        assert_eq!(medium.games_completed, 1);
+        assert_eq!(medium.average_secs(), Some(300));
+    }
+
+    #[test]
+    fn winning_rings_the_bell_exactly_once() {
+        let mut app = one_move_from_winning();
+        let feedback = MockFeedback::new();
+        app.feedback = Box::new(feedback.clone());
+
+        app.handle_key_event(KeyCode::Char('9').into());
+
+        assert_eq!(feedback.bell_count(), 1);
+    }
+
+    #[test]
+    fn winning_does_not_ring_the_bell_when_it_is_disabled() {
+        let mut app = one_move_from_winning();
+        app.settings.bell_enabled = false;
+        let feedback = MockFeedback::new();
+        app.feedback = Box::new(feedback.clone());
+
+        app.handle_key_event(KeyCode::Char('9').into());
+
+        assert_eq!(feedback.bell_count(), 0);
+    }
+
+    #[test]
+    fn toggling_daily_mode_on_the_menu_starts_the_same_puzzle_every_time() {
+        let mut app = App::default();
+        app.handle_key_event(KeyCode::Char('d').into());
+        assert!(app.daily_mode);
+
+        app.handle_key_event(KeyCode::Enter.into());
+        let first = app.game.board().clone();
+
+        let mut other = App {
+            daily_mode: true,
+            ..App::default()
+        };
+        other.handle_key_event(KeyCode::Enter.into());
+
+        assert_eq!(first, *other.game.board());
+    }
+
+    #[test]
+    fn three_wrong_entries_in_lives_mode_end_the_game() {
+        let mut solution = Board::default();
+        solution.set(0, 0, Cell::Given(5));
+        let mut app = App {
+            lives_mode: true,
+            lives_remaining: STARTING_LIVES,
+            game: Game {
+                solution: Some(solution),
+                ..Game::default()
+            },
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.set_cursor((0, 0));
+
+        app.handle_key_event(KeyCode::Char('7').into());
+        assert_eq!(app.game.board().get(0, 0), Cell::Filled(7));
+        assert_eq!(app.lives_remaining, 2);
+        assert_eq!(app.screen, Screen::Playing);
+
+        app.handle_key_event(KeyCode::Char('7').into());
+        assert_eq!(app.lives_remaining, 1);
+        assert_eq!(app.screen, Screen::Playing);
+
+        app.handle_key_event(KeyCode::Char('7').into());
+        assert_eq!(app.lives_remaining, 0);
+        assert_eq!(app.screen, Screen::GameOver);
     }
 
     #[test]
-    fn handle_key_event() -> io::Result<()> {
+    fn correct_entries_in_lives_mode_do_not_cost_a_life() {
+        let mut solution = Board::default();
+        solution.set(0, 0, Cell::Given(5));
+        let mut app = App {
+            lives_mode: true,
+            lives_remaining: STARTING_LIVES,
+            game: Game {
+                solution: Some(solution),
+                ..Game::default()
+            },
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.set_cursor((0, 0));
+
+        app.handle_key_event(KeyCode::Char('5').into());
+
+        assert_eq!(app.game.board().get(0, 0), Cell::Filled(5));
+        assert_eq!(app.lives_remaining, STARTING_LIVES);
+        assert_eq!(app.screen, Screen::Playing);
+    }
+
+    #[test]
+    fn stats_screen_opens_from_the_menu_and_closes_on_any_key() {
         let mut app = App::default();
-        app.handle_key_event(KeyCode::Char('q').into());
-        assert!(app.exit);
 
-        Ok(())
+        app.handle_key_event(KeyCode::Char('i').into());
+        assert_eq!(app.screen, Screen::Stats);
+
+        app.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(app.screen, Screen::Menu);
+    }
+
+    #[test]
+    fn load_screen_opens_from_the_menu_and_closes_on_escape() {
+        let mut app = App::default();
+
+        app.handle_key_event(KeyCode::Char('L').into());
+        assert_eq!(app.screen, Screen::Load);
+
+        app.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(app.screen, Screen::Menu);
+    }
+
+    #[test]
+    fn load_screen_navigation_clamps_at_either_end() {
+        let mut app = App {
+            screen: Screen::Load,
+            slots: vec![
+                persistence::SlotInfo {
+                    name: "first".to_string(),
+                    difficulty: Difficulty::Easy,
+                    elapsed: Duration::from_secs(30),
+                    saved_at_secs: 1,
+                },
+                persistence::SlotInfo {
+                    name: "second".to_string(),
+                    difficulty: Difficulty::Hard,
+                    elapsed: Duration::from_secs(90),
+                    saved_at_secs: 2,
+                },
+            ],
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Up.into());
+        assert_eq!(app.load_selection, 0);
+
+        app.handle_key_event(KeyCode::Down.into());
+        app.handle_key_event(KeyCode::Down.into());
+        assert_eq!(app.load_selection, 1);
+    }
+
+    #[test]
+    fn library_screen_opens_from_the_menu_and_closes_on_escape() {
+        let mut app = App::default();
+
+        app.handle_key_event(KeyCode::Char('b').into());
+        assert_eq!(app.screen, Screen::Library);
+        assert!(!app.library.is_empty());
+
+        app.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(app.screen, Screen::Menu);
+    }
+
+    #[test]
+    fn library_screen_navigation_clamps_at_either_end() {
+        let mut app = App::default();
+        app.open_library_screen();
+        let last = app.library.len() - 1;
+
+        app.handle_key_event(KeyCode::Up.into());
+        assert_eq!(app.library_selection, 0);
+
+        for _ in 0..app.library.len() + 1 {
+            app.handle_key_event(KeyCode::Down.into());
+        }
+        assert_eq!(app.library_selection, last);
+    }
+
+    #[test]
+    fn authoring_digits_become_givens_and_locking_starts_playing_them() {
+        let mut app = App {
+            screen: Screen::Authoring,
+            ..App::default()
+        };
+
+        for row in 0..9 {
+            for col in 0..9 {
+                let digit = ((row * 3 + row / 3 + col) % 9) as u8 + 1;
+                app.game.set_cursor((row, col));
+                app.handle_key_event(KeyCode::Char((b'0' + digit) as char).into());
+            }
+        }
+
+        assert_eq!(app.game.board().get(0, 0), Cell::Given(1));
+        assert_eq!(app.game.board().get(8, 8), Cell::Given(8));
+
+        app.handle_key_event(KeyCode::Enter.into());
+
+        assert_eq!(app.screen, Screen::Playing);
+        assert_eq!(app.game.board().get(0, 0), Cell::Given(1));
+        assert_eq!(app.game.board().get(8, 8), Cell::Given(8));
+        assert!(app.is_timer_running);
+    }
+
+    #[test]
+    fn authoring_refuses_to_lock_an_under_constrained_puzzle() {
+        let mut app = App {
+            screen: Screen::Authoring,
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 0, Cell::Given(5));
+
+        app.handle_key_event(KeyCode::Enter.into());
+
+        assert_eq!(app.screen, Screen::Authoring);
+        assert_eq!(app.game.board().get(0, 0), Cell::Given(5));
+    }
+
+    #[test]
+    fn loading_a_library_puzzle_starts_a_fresh_game_with_its_givens() {
+        let mut app = App {
+            game: Game {
+                moves: 5,
+                mistakes: 2,
+                ..Game::default()
+            },
+            screen: Screen::Library,
+            ..App::default()
+        };
+        app.library = library::load();
+        app.library_selection = 0;
+        let expected = app.library[0].clone();
+
+        app.handle_key_event(KeyCode::Enter.into());
+
+        assert_eq!(app.screen, Screen::Playing);
+        assert_eq!(app.difficulty, expected.difficulty);
+        assert_eq!(app.game.moves(), 0);
+        assert_eq!(app.game.mistakes(), 0);
+        assert_eq!(*app.game.board(), expected.board().unwrap());
+    }
+
+    #[test]
+    fn importing_from_the_clipboard_starts_a_fresh_game_with_its_givens() {
+        let puzzle = "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+        let mut app = App {
+            game: Game {
+                moves: 5,
+                mistakes: 2,
+                ..Game::default()
+            },
+            screen: Screen::Playing,
+            clipboard: Box::new(MockClipboard::new(Some(puzzle))),
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('I').into());
+
+        assert_eq!(app.screen, Screen::Playing);
+        assert_eq!(app.game.moves(), 0);
+        assert_eq!(app.game.mistakes(), 0);
+        assert_eq!(*app.game.board(), puzzle.parse::<Board>().unwrap());
+    }
+
+    #[test]
+    fn importing_an_empty_clipboard_shows_a_status_message_without_starting_a_game() {
+        let mut app = App {
+            game: Game {
+                moves: 5,
+                ..Game::default()
+            },
+            screen: Screen::Playing,
+            clipboard: Box::new(MockClipboard::new(None)),
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('I').into());
+
+        assert_eq!(app.screen, Screen::Playing);
+        assert_eq!(app.game.moves(), 5);
+        assert!(app.status.is_some());
+    }
+
+    #[test]
+    fn importing_a_clipboard_puzzle_with_conflicting_givens_is_rejected_without_starting_a_game() {
+        let puzzle = "55..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+        let mut app = App {
+            game: Game {
+                moves: 5,
+                ..Game::default()
+            },
+            screen: Screen::Playing,
+            clipboard: Box::new(MockClipboard::new(Some(puzzle))),
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('I').into());
+
+        assert_eq!(app.screen, Screen::Playing);
+        assert_eq!(app.game.moves(), 5);
+        assert_ne!(*app.game.board(), puzzle.parse::<Board>().unwrap());
+        assert!(app.status.is_some());
+    }
+
+    #[test]
+    fn a_hint_press_shows_the_technique_before_a_second_press_fills_the_cell() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+
+        let filled_before = |app: &App| {
+            (0..9)
+                .flat_map(|row| (0..9).map(move |col| (row, col)))
+                .filter(|&(row, col)| app.game.board().get(row, col).digit().is_some())
+                .count()
+        };
+        assert_eq!(filled_before(&app), 0);
+
+        app.handle_key_event(KeyCode::Char('H').into());
+
+        assert_eq!(filled_before(&app), 0);
+        assert_eq!(app.hints_used, 0);
+        assert!(app.pending_hint.is_some());
+
+        app.handle_key_event(KeyCode::Char('H').into());
+
+        assert_eq!(filled_before(&app), 1);
+        assert_eq!(app.hints_used, 1);
+        assert!(app.pending_hint.is_none());
+        assert!(app.game.board().conflicts().is_empty());
+    }
+
+    #[test]
+    fn toggling_peek_on_counts_toward_peeks_used_but_toggling_off_does_not() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('z').into());
+        assert!(app.peek_solution);
+        assert_eq!(app.peeks_used, 1);
+
+        app.handle_key_event(KeyCode::Char('z').into());
+        assert!(!app.peek_solution);
+        assert_eq!(app.peeks_used, 1);
+    }
+
+    #[test]
+    fn a_pending_hint_is_dropped_by_pressing_any_other_key() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+
+        app.handle_key_event(KeyCode::Char('H').into());
+        assert!(app.pending_hint.is_some());
+
+        app.handle_key_event(KeyCode::Down.into());
+
+        assert!(app.pending_hint.is_none());
+    }
+
+    #[test]
+    fn a_status_message_renders_below_the_grid_until_its_ttl_elapses() {
+        use ratatui::backend::TestBackend;
+
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.set_status("Saved", Duration::from_secs(3));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(60, 40)).unwrap();
+        terminal
+            .draw(|frame| render_playing_screen(frame, &mut app, Rect::new(0, 0, 60, 40)))
+            .unwrap();
+        let buf = terminal.backend().buffer().clone();
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("Saved"));
+
+        clock.advance(Duration::from_secs(3));
+
+        terminal
+            .draw(|frame| render_playing_screen(frame, &mut app, Rect::new(0, 0, 60, 40)))
+            .unwrap();
+        let buf = terminal.backend().buffer().clone();
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(!text.contains("Saved"));
+    }
+
+    #[test]
+    fn a_hint_on_a_board_solvable_by_a_hidden_single_identifies_it() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        for (col, digit) in (1..=6u8).enumerate() {
+            app.game.board_mut().set(0, col, Cell::Given(digit));
+        }
+        // Removes 9 from columns 6 and 8's candidates (from outside their
+        // box, so it doesn't also touch (0, 7)), leaving column 7 the only
+        // place left in row 0 for a 9 — a hidden single, not a naked one,
+        // since (0, 7) still has three other candidates.
+        app.game.board_mut().set(4, 6, Cell::Given(9));
+        app.game.board_mut().set(7, 8, Cell::Given(9));
+
+        app.handle_key_event(KeyCode::Char('H').into());
+
+        let pending = app.pending_hint.as_ref().expect("a hidden single should be found");
+        assert_eq!(pending.technique, "hidden single");
+        assert_eq!(pending.cells, [(0, 7)].into_iter().collect());
+        assert!(matches!(
+            pending.action,
+            HintAction::Fill { row: 0, col: 7, digit: 9 }
+        ));
+    }
+
+    #[test]
+    fn completing_a_row_correctly_flashes_it_but_an_incorrect_completion_does_not() {
+        let mut solution = Board::new(BoardSize::Classic9);
+        for (row, values) in SOLVED_GRID.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                solution.set(row, col, Cell::Given(value));
+            }
+        }
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.set_solution(Some(solution));
+        for (row, values) in SOLVED_GRID.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                let cell = if (row, col) == (0, 8) { Cell::Empty } else { Cell::Given(value) };
+                app.game.board_mut().set(row, col, cell);
+            }
+        }
+        app.game.set_cursor((0, 8));
+
+        let wrong_digit = SOLVED_GRID[0][8] % 9 + 1;
+        app.handle_key_event(KeyCode::Char(char::from_digit(wrong_digit as u32, 10).unwrap()).into());
+        assert!(app.flashing_units().is_empty());
+
+        app.game.board_mut().set(0, 8, Cell::Empty);
+        app.handle_key_event(KeyCode::Char(char::from_digit(SOLVED_GRID[0][8] as u32, 10).unwrap()).into());
+
+        let flashed = app.flashing_units();
+        for col in 0..9 {
+            assert!(flashed.contains(&(0, col)), "expected ({}, {col}) to flash", 0);
+        }
+    }
+
+    #[test]
+    fn check_progress_marks_exactly_the_one_wrong_entry() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        for (row, values) in SOLVED_GRID.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                app.game.board_mut().set(row, col, Cell::Given(value));
+            }
+        }
+        // One deliberately wrong entry, one correct entry, and one given.
+        app.game.board_mut().set(4, 4, Cell::Filled(1));
+        app.game.board_mut().set(5, 5, Cell::Filled(4));
+
+        app.handle_key_event(KeyCode::Char('C').into());
+
+        for row in 0..9 {
+            for col in 0..9 {
+                let expected = (row, col) == (4, 4);
+                assert_eq!(app.game.board().is_wrong(row, col), expected, "at ({row}, {col})");
+            }
+        }
+    }
+
+    #[test]
+    fn verify_notes_flags_only_cells_holding_an_incorrect_note() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        let mut solution = Board::new(BoardSize::Classic9);
+        for (row, values) in SOLVED_GRID.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                solution.set(row, col, Cell::Given(value));
+            }
+        }
+        app.game.set_solution(Some(solution));
+
+        // (0, 0) gets only its correct solution digit noted; (1, 1) gets
+        // its correct digit plus a wrong one.
+        app.game.board_mut().toggle_note(0, 0, SOLVED_GRID[0][0]);
+        app.game.board_mut().toggle_note(1, 1, SOLVED_GRID[1][1]);
+        app.game.board_mut().toggle_note(1, 1, SOLVED_GRID[1][1] % 9 + 1);
+
+        app.handle_key_event(KeyCode::Char('V').into());
+
+        assert!(!app.game.board().is_wrong(0, 0));
+        assert!(app.game.board().is_wrong(1, 1));
+    }
+
+    #[test]
+    fn auto_candidates_fills_empty_cells_and_recomputes_on_rerun() {
+        let mut app = one_move_from_winning();
+
+        app.handle_key_event(KeyCode::Char('a').into());
+
+        assert_eq!(app.game.board().notes(8, 8), &std::collections::HashSet::from([9]));
+
+        app.game.board_mut().set(8, 8, Cell::Filled(9));
+        app.game.board_mut().set(0, 0, Cell::Empty);
+        app.handle_key_event(KeyCode::Char('a').into());
+
+        assert!(app.game.board().notes(8, 8).is_empty());
+        assert_eq!(app.game.board().notes(0, 0), &std::collections::HashSet::from([5]));
+    }
+
+    #[test]
+    fn undoing_an_auto_candidates_sweep_reverts_every_cell_in_one_step() {
+        let mut app = one_move_from_winning();
+        app.game.board_mut().set(0, 0, Cell::Empty);
+        app.game.board_mut().set(0, 1, Cell::Empty);
+
+        app.handle_key_event(KeyCode::Char('a').into());
+        assert!(!app.game.board().notes(8, 8).is_empty());
+        assert!(!app.game.board().notes(0, 0).is_empty());
+        assert!(!app.game.board().notes(0, 1).is_empty());
+
+        app.handle_key_event(KeyCode::Char('u').into());
+
+        assert!(app.game.board().notes(8, 8).is_empty());
+        assert!(app.game.board().notes(0, 0).is_empty());
+        assert!(app.game.board().notes(0, 1).is_empty());
+    }
+
+    #[test]
+    fn fill_cell_notes_sets_only_the_selected_cells_notes_to_its_candidates() {
+        let mut app = one_move_from_winning();
+
+        app.handle_key_event(KeyCode::Char('f').into());
+
+        assert_eq!(app.game.board().notes(8, 8), &std::collections::HashSet::from([9]));
+        assert!(app.game.board().notes(0, 0).is_empty());
+    }
+
+    #[test]
+    fn fill_cell_notes_does_nothing_to_an_already_filled_cell() {
+        let mut app = one_move_from_winning();
+        app.game.set_cursor((0, 0));
+
+        app.handle_key_event(KeyCode::Char('f').into());
+
+        assert!(app.game.board().notes(0, 0).is_empty());
+    }
+
+    #[test]
+    fn tab_jumps_the_cursor_to_the_next_empty_cell_skipping_filled_ones() {
+        let mut app = one_move_from_winning();
+        app.game.board_mut().set(8, 8, Cell::Given(9));
+        app.game.board_mut().set(3, 6, Cell::Empty);
+        app.game.board_mut().set(3, 7, Cell::Empty);
+        app.game.set_cursor((3, 5));
+
+        app.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(app.game.cursor(), (3, 6));
+
+        app.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(app.game.cursor(), (3, 7));
+    }
+
+    #[test]
+    fn tab_cycles_only_the_focused_digits_candidate_cells_while_a_focus_is_active() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        for (row, values) in SOLVED_GRID.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                app.game.board_mut().set(row, col, Cell::Given(value));
+            }
+        }
+        // Clearing every cell that held a 4 leaves each one as the only
+        // legal spot for 4 in its own row, column, and box.
+        app.game.board_mut().set(0, 2, Cell::Empty);
+        app.game.board_mut().set(1, 7, Cell::Empty);
+        app.game.board_mut().set(2, 4, Cell::Empty);
+        // An empty cell that isn't a candidate for 4, since 4 is still
+        // present elsewhere in its row, column, and box.
+        app.game.board_mut().set(8, 8, Cell::Empty);
+        app.digit_focus = Some(4);
+        app.game.set_cursor((0, 0));
+
+        app.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(app.game.cursor(), (0, 2));
+
+        app.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(app.game.cursor(), (1, 7));
+
+        app.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(app.game.cursor(), (2, 4));
+
+        app.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(app.game.cursor(), (0, 2));
+    }
+
+    #[test]
+    fn tab_wraps_around_from_the_last_cell_back_to_the_first() {
+        let mut app = one_move_from_winning();
+        app.game.board_mut().set(0, 0, Cell::Empty);
+        app.game.set_cursor((8, 8));
+
+        app.handle_key_event(KeyCode::Tab.into());
+
+        assert_eq!(app.game.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn shift_tab_jumps_the_cursor_to_the_previous_empty_cell() {
+        let mut app = one_move_from_winning();
+        app.game.board_mut().set(0, 0, Cell::Empty);
+        app.game.set_cursor((8, 8));
+
+        app.handle_key_event(KeyCode::BackTab.into());
+
+        assert_eq!(app.game.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn tab_is_a_no_op_on_a_full_board() {
+        let mut app = one_move_from_winning();
+        app.game.board_mut().set(8, 8, Cell::Given(9));
+        app.game.set_cursor((4, 4));
+
+        app.handle_key_event(KeyCode::Tab.into());
+
+        assert_eq!(app.game.cursor(), (4, 4));
+    }
+
+    #[test]
+    fn jump_to_conflict_cycles_through_conflicting_cells_in_reading_order() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.board_mut().set(0, 0, Cell::Given(1));
+        app.game.board_mut().set(0, 1, Cell::Given(1));
+        app.game.board_mut().set(3, 0, Cell::Given(2));
+        app.game.board_mut().set(4, 0, Cell::Given(2));
+        app.game.set_cursor((0, 0));
+
+        app.handle_key_event(KeyCode::Char('X').into());
+        assert_eq!(app.game.cursor(), (0, 1));
+
+        app.handle_key_event(KeyCode::Char('X').into());
+        assert_eq!(app.game.cursor(), (3, 0));
+
+        app.handle_key_event(KeyCode::Char('X').into());
+        assert_eq!(app.game.cursor(), (4, 0));
+
+        app.handle_key_event(KeyCode::Char('X').into());
+        assert_eq!(app.game.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn jump_to_conflict_does_nothing_when_the_board_has_no_conflicts() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.game.set_cursor((4, 4));
+
+        app.handle_key_event(KeyCode::Char('X').into());
+
+        assert_eq!(app.game.cursor(), (4, 4));
+    }
+
+    #[test]
+    fn naked_singles_fills_every_cell_that_is_forced_and_only_those() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        for (row, values) in SOLVED_GRID.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                app.game.board_mut().set(row, col, Cell::Given(value));
+            }
+        }
+        // Each of these is the last empty cell in its row, so it's forced
+        // regardless of what happens elsewhere on the board.
+        app.game.board_mut().set(7, 7, Cell::Empty);
+        app.game.board_mut().set(8, 8, Cell::Empty);
+
+        app.handle_key_event(KeyCode::Char('N').into());
+
+        assert_eq!(app.game.board().get(7, 7), Cell::Filled(SOLVED_GRID[7][7]));
+        assert_eq!(app.game.board().get(8, 8), Cell::Filled(SOLVED_GRID[8][8]));
+        assert!(app.game.is_won());
+    }
+
+    #[test]
+    fn coaching_cells_finds_exactly_the_cells_with_one_legal_candidate() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        for (row, values) in SOLVED_GRID.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                app.game.board_mut().set(row, col, Cell::Given(value));
+            }
+        }
+        // Emptying whole rows 0 and 1 leaves every one of their cells with
+        // several possible candidates, while these two remain the last
+        // empty cell in their row and so are each forced to one candidate.
+        for col in 0..9 {
+            app.game.board_mut().set(0, col, Cell::Empty);
+            app.game.board_mut().set(1, col, Cell::Empty);
+        }
+        app.game.board_mut().set(7, 7, Cell::Empty);
+        app.game.board_mut().set(8, 8, Cell::Empty);
+
+        let coaching_cells = app.coaching_cells();
+
+        assert_eq!(coaching_cells, [(7, 7), (8, 8)].into_iter().collect());
+    }
+
+    #[test]
+    fn hidden_singles_fills_a_cell_confined_to_one_digit_in_its_row() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        for (col, digit) in (1..=7u8).enumerate() {
+            app.game.board_mut().set(0, col, Cell::Given(digit));
+        }
+        // Removes 9 from column 8's candidates, so within row 0 only column
+        // 7 can still hold it, even though column 7 also has candidate 8.
+        app.game.board_mut().set(5, 8, Cell::Given(9));
+
+        app.handle_key_event(KeyCode::Char('g').into());
+
+        assert_eq!(app.game.board().get(0, 7), Cell::Filled(9));
+    }
+
+    #[test]
+    fn pointing_pairs_removes_eliminated_candidates_from_existing_notes() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        // Box (0, 0)'s rows 1 and 2 are entirely filled with givens other
+        // than 5, so within that box only row 0 can still hold a 5.
+        app.game.board_mut().set(1, 0, Cell::Given(1));
+        app.game.board_mut().set(1, 1, Cell::Given(2));
+        app.game.board_mut().set(1, 2, Cell::Given(3));
+        app.game.board_mut().set(2, 0, Cell::Given(4));
+        app.game.board_mut().set(2, 1, Cell::Given(6));
+        app.game.board_mut().set(2, 2, Cell::Given(7));
+        app.game.board_mut().toggle_note(0, 4, 5);
+
+        app.handle_key_event(KeyCode::Char('b').into());
+
+        assert!(!app.game.board().notes(0, 4).contains(&5));
+    }
+
+    #[test]
+    fn hidden_pairs_removes_other_candidates_from_the_two_confined_cells_notes() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        // Column 0's rows 2-7 are givens using every digit except 5, 6, and
+        // 9, so within that column only rows 0 and 1 can still hold 5 or 6.
+        app.game.board_mut().set(2, 0, Cell::Given(1));
+        app.game.board_mut().set(3, 0, Cell::Given(2));
+        app.game.board_mut().set(4, 0, Cell::Given(3));
+        app.game.board_mut().set(5, 0, Cell::Given(4));
+        app.game.board_mut().set(6, 0, Cell::Given(7));
+        app.game.board_mut().set(7, 0, Cell::Given(8));
+        app.game.board_mut().set(6, 1, Cell::Given(5));
+        app.game.board_mut().set(7, 1, Cell::Given(6));
+        app.game.board_mut().toggle_note(0, 0, 9);
+        app.game.board_mut().toggle_note(1, 0, 9);
+
+        app.handle_key_event(KeyCode::Char('P').into());
+
+        assert!(!app.game.board().notes(0, 0).contains(&9));
+        assert!(!app.game.board().notes(1, 0).contains(&9));
+    }
+
+    #[test]
+    fn x_wing_removes_the_digit_from_the_rest_of_the_two_confined_columns_notes() {
+        let mut app = App {
+            is_timer_running: true,
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        // Rows 0 and 4 confine 9 to columns 2 and 6, so it can be
+        // eliminated from column 2 and 6 everywhere else.
+        for &row in &[0, 4] {
+            for col in [0, 1, 3, 4, 5, 7, 8] {
+                app.game.board_mut().set(row, col, Cell::Given(1));
+            }
+        }
+        app.game.board_mut().toggle_note(8, 2, 9);
+        app.game.board_mut().toggle_note(8, 6, 9);
+
+        app.handle_key_event(KeyCode::Char('w').into());
+
+        assert!(!app.game.board().notes(8, 2).contains(&9));
+        assert!(!app.game.board().notes(8, 6).contains(&9));
+    }
+
+    #[test]
+    fn replay_steps_forward_and_backward_through_the_recorded_moves() {
+        let clock = MockClock::new();
+        let mut app = App {
+            clock: Box::new(clock.clone()),
+            screen: Screen::Playing,
+            ..App::default()
+        };
+        app.start_timer();
+        app.start_board = Some(app.game.board().clone());
+
+        app.game.set_cursor((0, 0));
+        app.handle_key_event(KeyCode::Char('1').into());
+        clock.advance(Duration::from_secs(10));
+
+        app.game.set_cursor((0, 1));
+        app.handle_key_event(KeyCode::Char('2').into());
+        clock.advance(Duration::from_secs(10));
+
+        app.game.set_cursor((0, 2));
+        app.handle_key_event(KeyCode::Char('3').into());
+
+        assert_eq!(app.move_history.len(), 3);
+        let timestamps: Vec<Duration> = app.move_history.iter().map(|(at, _)| *at).collect();
+        assert!(timestamps[0] < timestamps[1] && timestamps[1] < timestamps[2]);
+
+        app.screen = Screen::Won;
+        app.handle_key_event(KeyCode::Char('y').into());
+        assert_eq!(app.screen, Screen::Replay);
+        assert_eq!(app.replay_step, 0);
+
+        let board = app.replay_board();
+        assert_eq!(board.get(0, 0), Cell::Empty);
+        assert_eq!(board.get(0, 1), Cell::Empty);
+        assert_eq!(board.get(0, 2), Cell::Empty);
+
+        app.handle_replay_key(KeyCode::Right.into());
+        let board = app.replay_board();
+        assert_eq!(board.get(0, 0), Cell::Filled(1));
+        assert_eq!(board.get(0, 1), Cell::Empty);
+        assert_eq!(board.get(0, 2), Cell::Empty);
+
+        app.handle_replay_key(KeyCode::Char('l').into());
+        let board = app.replay_board();
+        assert_eq!(board.get(0, 0), Cell::Filled(1));
+        assert_eq!(board.get(0, 1), Cell::Filled(2));
+        assert_eq!(board.get(0, 2), Cell::Empty);
+
+        app.handle_replay_key(KeyCode::Right.into());
+        let board = app.replay_board();
+        assert_eq!(board.get(0, 2), Cell::Filled(3));
+
+        app.handle_replay_key(KeyCode::Right.into());
+        assert_eq!(app.replay_step, 3);
+
+        app.handle_replay_key(KeyCode::Left.into());
+        app.handle_replay_key(KeyCode::Left.into());
+        let board = app.replay_board();
+        assert_eq!(board.get(0, 0), Cell::Filled(1));
+        assert_eq!(board.get(0, 1), Cell::Empty);
+
+        app.handle_replay_key(KeyCode::Esc.into());
+        assert_eq!(app.screen, Screen::Won);
     }
 }
+
+
@@ -0,0 +1,303 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::board::Board;
+use crate::generator::Difficulty;
+
+#[derive(Serialize, Deserialize)]
+struct SavedGame {
+    board: Board,
+    elapsed_millis: u64,
+}
+
+/// Writes `board` and `elapsed` to `path` as JSON, creating parent
+/// directories as needed. Elapsed is stored to millisecond precision so a
+/// record set mid-game doesn't lose its sub-second edge on save/reload.
+pub fn save(board: &Board, elapsed: Duration, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let saved = SavedGame {
+        board: board.clone(),
+        elapsed_millis: elapsed.as_millis() as u64,
+    };
+    let json = serde_json::to_string_pretty(&saved).map_err(io::Error::other)?;
+    fs::write(path, json)
+}
+
+/// Reads back a board and elapsed time previously written by `save`.
+pub fn load(path: &Path) -> io::Result<(Board, Duration)> {
+    let json = fs::read_to_string(path)?;
+    let saved: SavedGame = serde_json::from_str(&json).map_err(io::Error::other)?;
+    Ok((saved.board, Duration::from_millis(saved.elapsed_millis)))
+}
+
+/// The default save location, under the OS config directory.
+pub fn default_save_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("save.json")
+}
+
+/// Writes `board`'s boxed ASCII rendering to `path`, creating parent
+/// directories as needed, so a player can share the current puzzle.
+pub fn export_ascii(board: &Board, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, board.to_ascii_grid())
+}
+
+/// The default location an ASCII export is written to, under the OS config
+/// directory.
+pub fn default_export_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("board.txt")
+}
+
+/// Writes `summary` (a spoiler-free line like `App::result_summary` produces)
+/// to `path`, creating parent directories as needed, so a player can share it
+/// without exposing the solved grid.
+pub fn export_result_summary(summary: &str, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, summary)
+}
+
+/// The default location a result summary is written to, under the OS config
+/// directory.
+pub fn default_result_summary_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("result.txt")
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedSlot {
+    board: Board,
+    elapsed_millis: u64,
+    difficulty: Difficulty,
+    saved_at_secs: u64,
+}
+
+/// A saved slot's metadata, without its (potentially large) board, for
+/// listing on the load screen.
+#[derive(Debug, Clone)]
+pub struct SlotInfo {
+    pub name: String,
+    pub difficulty: Difficulty,
+    pub elapsed: Duration,
+    pub saved_at_secs: u64,
+}
+
+/// Writes `board`, `elapsed`, and `difficulty` to a named slot under `dir`,
+/// alongside whatever other slots already exist there, creating `dir` as
+/// needed. Unlike `save`, this never overwrites another slot.
+pub fn save_slot(name: &str, board: &Board, elapsed: Duration, difficulty: Difficulty, dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let saved_at_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_secs())
+        .unwrap_or(0);
+    let saved = SavedSlot {
+        board: board.clone(),
+        elapsed_millis: elapsed.as_millis() as u64,
+        difficulty,
+        saved_at_secs,
+    };
+    let json = serde_json::to_string_pretty(&saved).map_err(io::Error::other)?;
+    fs::write(dir.join(format!("{name}.json")), json)
+}
+
+/// Reads back a board, elapsed time, and difficulty previously written by
+/// `save_slot`.
+pub fn load_slot(name: &str, dir: &Path) -> io::Result<(Board, Duration, Difficulty)> {
+    let json = fs::read_to_string(dir.join(format!("{name}.json")))?;
+    let saved: SavedSlot = serde_json::from_str(&json).map_err(io::Error::other)?;
+    Ok((saved.board, Duration::from_millis(saved.elapsed_millis), saved.difficulty))
+}
+
+/// Every slot saved under `dir`, sorted by name, skipping any file that
+/// isn't a slot `save_slot` could have written (e.g. left over from another
+/// program, or corrupted).
+pub fn list_slots(dir: &Path) -> Vec<SlotInfo> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut slots: Vec<SlotInfo> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            let name = path.file_stem()?.to_str()?.to_string();
+            let json = fs::read_to_string(&path).ok()?;
+            let saved: SavedSlot = serde_json::from_str(&json).ok()?;
+            Some(SlotInfo {
+                name,
+                difficulty: saved.difficulty,
+                elapsed: Duration::from_millis(saved.elapsed_millis),
+                saved_at_secs: saved.saved_at_secs,
+            })
+        })
+        .collect();
+    slots.sort_by_key(|slot| std::cmp::Reverse(slot.saved_at_secs));
+    slots
+}
+
+/// The default directory named save slots live in, under the OS config
+/// directory.
+pub fn default_slots_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("slots")
+}
+
+/// Reads a puzzle to start playing from `path`, accepting either a plain
+/// puzzle line or a boxed ASCII grid like `export_ascii` writes: every
+/// character other than a digit or `.` is stripped out before parsing, so
+/// the grid's border and spacing are simply ignored.
+pub fn load_puzzle(path: &Path) -> io::Result<Board> {
+    let contents = fs::read_to_string(path)?;
+    let line: String = contents.chars().filter(|c| c.is_ascii_digit() || *c == '.').collect();
+    line.parse().map_err(io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Cell;
+
+    #[test]
+    fn save_and_load_round_trips_a_mid_game_board() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(1, 1, Cell::Filled(3));
+        board.toggle_note(2, 2, 7);
+
+        let dir = std::env::temp_dir().join(format!("rusuku-test-{}", std::process::id()));
+        let path = dir.join("save.json");
+
+        save(&board, Duration::from_millis(125_250), &path).unwrap();
+        let (loaded_board, elapsed) = load(&path).unwrap();
+
+        assert_eq!(loaded_board, board);
+        assert_eq!(elapsed, Duration::from_millis(125_250));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn export_ascii_writes_the_boards_ascii_grid_to_a_file() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+
+        let dir = std::env::temp_dir().join(format!("rusuku-export-test-{}", std::process::id()));
+        let path = dir.join("board.txt");
+
+        export_ascii(&board, &path).unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), board.to_ascii_grid());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn export_result_summary_writes_the_summary_text_to_a_file() {
+        let dir = std::env::temp_dir().join(format!("rusuku-result-summary-test-{}", std::process::id()));
+        let path = dir.join("result.txt");
+
+        export_result_summary("Rusuku Hard 07:43 ✅ 0 hints, 0 mistakes", &path).unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Rusuku Hard 07:43 ✅ 0 hints, 0 mistakes");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn load_puzzle_reads_back_a_boxed_ascii_grid_as_the_same_board() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(4, 4, Cell::Given(9));
+
+        let dir = std::env::temp_dir().join(format!("rusuku-load-puzzle-test-{}", std::process::id()));
+        let path = dir.join("puzzle.txt");
+        export_ascii(&board, &path).unwrap();
+
+        let loaded = load_puzzle(&path).unwrap();
+
+        assert_eq!(loaded, board);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn save_slot_and_load_slot_round_trip_a_named_slot() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+
+        let dir = std::env::temp_dir().join(format!("rusuku-slot-test-{}", std::process::id()));
+
+        save_slot("first", &board, Duration::from_secs(60), Difficulty::Hard, &dir).unwrap();
+        let (loaded_board, elapsed, difficulty) = load_slot("first", &dir).unwrap();
+
+        assert_eq!(loaded_board, board);
+        assert_eq!(elapsed, Duration::from_secs(60));
+        assert_eq!(difficulty, Difficulty::Hard);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn list_slots_returns_every_saved_slot_with_its_metadata() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+
+        let dir = std::env::temp_dir().join(format!("rusuku-list-slots-test-{}", std::process::id()));
+
+        save_slot("easy-game", &board, Duration::from_secs(30), Difficulty::Easy, &dir).unwrap();
+        save_slot("hard-game", &board, Duration::from_secs(90), Difficulty::Hard, &dir).unwrap();
+
+        let mut slots = list_slots(&dir);
+        slots.sort_by(|a, b| a.name.cmp(&b.name));
+
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0].name, "easy-game");
+        assert_eq!(slots[0].difficulty, Difficulty::Easy);
+        assert_eq!(slots[0].elapsed, Duration::from_secs(30));
+        assert_eq!(slots[1].name, "hard-game");
+        assert_eq!(slots[1].difficulty, Difficulty::Hard);
+        assert_eq!(slots[1].elapsed, Duration::from_secs(90));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn list_slots_returns_empty_for_a_missing_directory() {
+        let dir = std::env::temp_dir().join("rusuku-list-slots-test-missing-dir");
+
+        assert!(list_slots(&dir).is_empty());
+    }
+
+    #[test]
+    fn load_puzzle_rejects_a_file_that_does_not_parse_as_a_board() {
+        let dir = std::env::temp_dir().join(format!("rusuku-load-puzzle-bad-test-{}", std::process::id()));
+        let path = dir.join("puzzle.txt");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "not a puzzle").unwrap();
+
+        assert!(load_puzzle(&path).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
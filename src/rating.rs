@@ -0,0 +1,492 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::board::Board;
+use crate::generator::Difficulty;
+
+/// Which human solving technique found a cell's value, ordered from
+/// easiest to hardest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Technique {
+    NakedSingle,
+    HiddenSingle,
+    PointingPair,
+    HiddenPair,
+    XWing,
+}
+
+/// Rates how hard `board` is to solve by attempting it with increasingly
+/// advanced human techniques (naked singles, then hidden singles, then
+/// pointing pairs, then hidden pairs, then X-Wings) and reporting the
+/// hardest one needed. A puzzle that can't be finished with these
+/// techniques alone — it would need guessing — is rated
+/// `Difficulty::Expert`, the hardest label available.
+pub fn rate(board: &Board) -> Difficulty {
+    let size = board.size();
+    let side = size.side();
+    let box_dims = size.box_dims();
+
+    let mut filled = vec![vec![false; side]; side];
+    let mut candidates = vec![vec![HashSet::new(); side]; side];
+    for row in 0..side {
+        for col in 0..side {
+            if board.get(row, col).digit().is_some() {
+                filled[row][col] = true;
+            } else {
+                candidates[row][col] = board.candidates(row, col);
+            }
+        }
+    }
+
+    let mut hardest: Option<Technique> = None;
+
+    loop {
+        if filled.iter().flatten().all(|&is_filled| is_filled) {
+            break;
+        }
+
+        let technique = if let Some((row, col, digit)) = find_naked_single(&candidates, &filled, side) {
+            place(&mut candidates, &mut filled, row, col, digit, side, box_dims);
+            Technique::NakedSingle
+        } else if let Some((row, col, digit)) = find_hidden_single(&candidates, &filled, side, box_dims) {
+            place(&mut candidates, &mut filled, row, col, digit, side, box_dims);
+            Technique::HiddenSingle
+        } else if eliminate_pointing_pairs(&mut candidates, &filled, side, box_dims) {
+            Technique::PointingPair
+        } else if eliminate_hidden_pairs(&mut candidates, &filled, side, box_dims) {
+            Technique::HiddenPair
+        } else if eliminate_x_wing(&mut candidates, &filled, side) {
+            Technique::XWing
+        } else {
+            // None of our techniques make progress: this puzzle needs guessing.
+            return Difficulty::Expert;
+        };
+
+        hardest = Some(hardest.map_or(technique, |h| h.max(technique)));
+    }
+
+    match hardest {
+        None | Some(Technique::NakedSingle) => Difficulty::Easy,
+        Some(Technique::HiddenSingle) => Difficulty::Medium,
+        Some(Technique::PointingPair) => Difficulty::Hard,
+        Some(Technique::HiddenPair) | Some(Technique::XWing) => Difficulty::Expert,
+    }
+}
+
+/// Marks `(row, col)` filled with `digit` and removes it from its peers'
+/// candidate sets.
+#[allow(clippy::needless_range_loop)]
+fn place(
+    candidates: &mut [Vec<HashSet<u8>>],
+    filled: &mut [Vec<bool>],
+    row: usize,
+    col: usize,
+    digit: u8,
+    side: usize,
+    box_dims: (usize, usize),
+) {
+    filled[row][col] = true;
+    candidates[row][col].clear();
+
+    let (box_rows, box_cols) = box_dims;
+    let box_row = (row / box_rows) * box_rows;
+    let box_col = (col / box_cols) * box_cols;
+    for i in 0..side {
+        candidates[row][i].remove(&digit);
+        candidates[i][col].remove(&digit);
+    }
+    for r in box_row..box_row + box_rows {
+        for c in box_col..box_col + box_cols {
+            candidates[r][c].remove(&digit);
+        }
+    }
+}
+
+/// A cell left with exactly one candidate: it must hold that digit.
+fn find_naked_single(
+    candidates: &[Vec<HashSet<u8>>],
+    filled: &[Vec<bool>],
+    side: usize,
+) -> Option<(usize, usize, u8)> {
+    for row in 0..side {
+        for col in 0..side {
+            if !filled[row][col] && candidates[row][col].len() == 1 {
+                let digit = *candidates[row][col].iter().next().expect("checked len == 1");
+                return Some((row, col, digit));
+            }
+        }
+    }
+    None
+}
+
+/// A digit that has only one possible cell left within some row, column,
+/// or box, even if that cell also has other candidates. The same technique
+/// as `Board::hidden_singles`, but working against this module's own
+/// candidate-grid simulation rather than a live board.
+fn find_hidden_single(
+    candidates: &[Vec<HashSet<u8>>],
+    filled: &[Vec<bool>],
+    side: usize,
+    box_dims: (usize, usize),
+) -> Option<(usize, usize, u8)> {
+    for unit in units(side, box_dims) {
+        for digit in 1..=side as u8 {
+            let mut holders = unit
+                .iter()
+                .copied()
+                .filter(|&(r, c)| !filled[r][c] && candidates[r][c].contains(&digit));
+            let Some(first) = holders.next() else {
+                continue;
+            };
+            if holders.next().is_none() {
+                return Some((first.0, first.1, digit));
+            }
+        }
+    }
+    None
+}
+
+/// Within each box, if a digit's remaining candidate cells all share a row
+/// or column, that digit can't appear anywhere else in that row/column
+/// outside the box, so it's eliminated from those cells too. Returns
+/// whether any candidate was actually removed.
+#[allow(clippy::needless_range_loop)]
+fn eliminate_pointing_pairs(
+    candidates: &mut [Vec<HashSet<u8>>],
+    filled: &[Vec<bool>],
+    side: usize,
+    box_dims: (usize, usize),
+) -> bool {
+    let (box_rows, box_cols) = box_dims;
+    let boxes_per_row = side / box_cols;
+    let mut changed = false;
+
+    for b in 0..side {
+        let box_row = (b / boxes_per_row) * box_rows;
+        let box_col = (b % boxes_per_row) * box_cols;
+        let cells: Vec<(usize, usize)> = (box_row..box_row + box_rows)
+            .flat_map(|r| (box_col..box_col + box_cols).map(move |c| (r, c)))
+            .collect();
+
+        for digit in 1..=side as u8 {
+            let holders: Vec<(usize, usize)> = cells
+                .iter()
+                .copied()
+                .filter(|&(r, c)| !filled[r][c] && candidates[r][c].contains(&digit))
+                .collect();
+            if holders.len() < 2 {
+                continue;
+            }
+
+            if holders.iter().all(|&(r, _)| r == holders[0].0) {
+                let row = holders[0].0;
+                for col in 0..side {
+                    if (box_col..box_col + box_cols).contains(&col) {
+                        continue;
+                    }
+                    if !filled[row][col] && candidates[row][col].remove(&digit) {
+                        changed = true;
+                    }
+                }
+            } else if holders.iter().all(|&(_, c)| c == holders[0].1) {
+                let col = holders[0].1;
+                for row in 0..side {
+                    if (box_row..box_row + box_rows).contains(&row) {
+                        continue;
+                    }
+                    if !filled[row][col] && candidates[row][col].remove(&digit) {
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    changed
+}
+
+/// Within a unit, if two digits' remaining candidate cells are exactly the
+/// same two cells, every other candidate can be eliminated from those two
+/// cells. The same technique as `Board::hidden_pairs`, but working against
+/// this module's own candidate-grid simulation rather than a live board.
+/// Returns whether any candidate was actually removed.
+fn eliminate_hidden_pairs(
+    candidates: &mut [Vec<HashSet<u8>>],
+    filled: &[Vec<bool>],
+    side: usize,
+    box_dims: (usize, usize),
+) -> bool {
+    let mut changed = false;
+
+    for unit in units(side, box_dims) {
+        for d1 in 1..=side as u8 {
+            let d1_holders: Vec<(usize, usize)> = unit
+                .iter()
+                .copied()
+                .filter(|&(r, c)| !filled[r][c] && candidates[r][c].contains(&d1))
+                .collect();
+            if d1_holders.len() != 2 {
+                continue;
+            }
+
+            for d2 in (d1 + 1)..=side as u8 {
+                let d2_holders: Vec<(usize, usize)> = unit
+                    .iter()
+                    .copied()
+                    .filter(|&(r, c)| !filled[r][c] && candidates[r][c].contains(&d2))
+                    .collect();
+                if d2_holders != d1_holders {
+                    continue;
+                }
+
+                for &(row, col) in &d1_holders {
+                    let before = candidates[row][col].len();
+                    candidates[row][col].retain(|&digit| digit == d1 || digit == d2);
+                    changed |= candidates[row][col].len() != before;
+                }
+            }
+        }
+    }
+
+    changed
+}
+
+/// If a digit's remaining candidates in two rows are confined to the same
+/// two columns (or, symmetrically, two columns confined to the same two
+/// rows), one of those rows must place the digit in each column, so it can
+/// be eliminated from every other cell in those columns. The same
+/// technique as `Board::x_wing`, but working against this module's own
+/// candidate-grid simulation rather than a live board. Returns whether any
+/// candidate was actually removed.
+fn eliminate_x_wing(candidates: &mut [Vec<HashSet<u8>>], filled: &[Vec<bool>], side: usize) -> bool {
+    let mut changed = false;
+
+    for digit in 1..=side as u8 {
+        let mut rows_by_columns: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+        for row in 0..side {
+            let cols: Vec<usize> = (0..side).filter(|&col| !filled[row][col] && candidates[row][col].contains(&digit)).collect();
+            if cols.len() == 2 {
+                rows_by_columns.entry(cols).or_default().push(row);
+            }
+        }
+        for (cols, rows) in &rows_by_columns {
+            if rows.len() != 2 {
+                continue;
+            }
+            for &col in cols {
+                for row in 0..side {
+                    if rows.contains(&row) {
+                        continue;
+                    }
+                    if !filled[row][col] && candidates[row][col].remove(&digit) {
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        let mut cols_by_rows: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+        for col in 0..side {
+            let rows: Vec<usize> = (0..side).filter(|&row| !filled[row][col] && candidates[row][col].contains(&digit)).collect();
+            if rows.len() == 2 {
+                cols_by_rows.entry(rows).or_default().push(col);
+            }
+        }
+        for (rows, cols) in &cols_by_rows {
+            if cols.len() != 2 {
+                continue;
+            }
+            for &row in rows {
+                for col in 0..side {
+                    if cols.contains(&col) {
+                        continue;
+                    }
+                    if !filled[row][col] && candidates[row][col].remove(&digit) {
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    changed
+}
+
+/// Every row, column, and box of the board as a list of its cell
+/// coordinates, mirroring `Board::conflicts`'s unit iteration.
+fn units(side: usize, box_dims: (usize, usize)) -> Vec<Vec<(usize, usize)>> {
+    let (box_rows, box_cols) = box_dims;
+    let boxes_per_row = side / box_cols;
+
+    let rows = (0..side).map(|row| (0..side).map(move |col| (row, col)).collect::<Vec<_>>());
+    let cols = (0..side).map(|col| (0..side).map(move |row| (row, col)).collect::<Vec<_>>());
+    let boxes = (0..side).map(move |b| {
+        let box_row = (b / boxes_per_row) * box_rows;
+        let box_col = (b % boxes_per_row) * box_cols;
+        (box_row..box_row + box_rows)
+            .flat_map(move |row| (box_col..box_col + box_cols).map(move |col| (row, col)))
+            .collect::<Vec<_>>()
+    });
+
+    rows.chain(cols).chain(boxes).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Cell;
+
+    fn board_from_grid(grid: [[u8; 9]; 9]) -> Board {
+        let mut board = Board::default();
+        for (row, values) in grid.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                if value != 0 {
+                    board.set(row, col, Cell::Given(value));
+                }
+            }
+        }
+        board
+    }
+
+    #[test]
+    fn a_puzzle_missing_only_one_cell_rates_easy() {
+        let board = board_from_grid([
+            [5, 3, 4, 6, 7, 8, 9, 1, 2],
+            [6, 7, 2, 1, 9, 5, 3, 4, 8],
+            [1, 9, 8, 3, 4, 2, 5, 6, 7],
+            [8, 5, 9, 7, 6, 1, 4, 2, 3],
+            [4, 2, 6, 8, 5, 3, 7, 9, 1],
+            [7, 1, 3, 9, 2, 4, 8, 5, 6],
+            [9, 6, 1, 5, 3, 7, 2, 8, 4],
+            [2, 8, 7, 4, 1, 9, 6, 3, 5],
+            [3, 4, 5, 2, 8, 6, 1, 0, 9],
+        ]);
+
+        assert_eq!(rate(&board), Difficulty::Easy);
+    }
+
+    #[test]
+    fn find_naked_single_ignores_cells_with_more_than_one_candidate() {
+        let side = 4;
+        let filled = vec![vec![false; side]; side];
+        let mut candidates = vec![vec![HashSet::new(); side]; side];
+        candidates[0][0] = HashSet::from([1, 2]);
+        candidates[1][1] = HashSet::from([3]);
+
+        assert_eq!(find_naked_single(&candidates, &filled, side), Some((1, 1, 3)));
+    }
+
+    #[test]
+    fn find_hidden_single_spots_a_digit_confined_to_one_cell_in_a_box() {
+        let side = 4;
+        let box_dims = (2, 2);
+        let filled = vec![vec![false; side]; side];
+        // Every cell outside the top-left box can (per this hand-built
+        // scenario) still hold any digit, so it never looks uniquely
+        // confined anywhere by accident.
+        let mut candidates = vec![vec![HashSet::from([1, 2, 3, 4]); side]; side];
+        // Within the top-left box, every cell can hold 1, 2, or 3, except
+        // (0, 0) which can also hold 4 — the only cell in the box that
+        // can, so 4 is a hidden single there even though (0, 0) isn't a
+        // naked single.
+        candidates[0][0] = HashSet::from([1, 2, 4]);
+        candidates[0][1] = HashSet::from([1, 3]);
+        candidates[1][0] = HashSet::from([2, 3]);
+        candidates[1][1] = HashSet::from([1, 2, 3]);
+
+        assert_eq!(
+            find_hidden_single(&candidates, &filled, side, box_dims),
+            Some((0, 0, 4))
+        );
+    }
+
+    #[test]
+    #[allow(clippy::needless_range_loop)]
+    fn pointing_pair_removes_the_digit_from_the_rest_of_the_row() {
+        let side = 9;
+        let box_dims = (3, 3);
+        let filled = vec![vec![false; side]; side];
+        let mut candidates = vec![vec![HashSet::new(); side]; side];
+        for col in 0..side {
+            candidates[0][col] = HashSet::from([5, 6]);
+        }
+        // Within the top-left box, only row 0's cells can hold 5.
+        candidates[1][0] = HashSet::from([6]);
+        candidates[1][1] = HashSet::from([6]);
+        candidates[1][2] = HashSet::from([6]);
+        candidates[2][0] = HashSet::from([6]);
+        candidates[2][1] = HashSet::from([6]);
+        candidates[2][2] = HashSet::from([6]);
+
+        assert!(eliminate_pointing_pairs(&mut candidates, &filled, side, box_dims));
+        for col in 3..side {
+            assert!(!candidates[0][col].contains(&5), "at column {col}");
+        }
+    }
+
+    #[test]
+    #[allow(clippy::needless_range_loop)]
+    fn eliminate_hidden_pairs_strips_other_candidates_from_the_two_confined_cells() {
+        let side = 9;
+        let box_dims = (3, 3);
+        let filled = vec![vec![false; side]; side];
+        let mut candidates = vec![vec![HashSet::new(); side]; side];
+        // Only column 0's rows 0 and 1 can hold 5 or 6, but both also list
+        // 9 as a candidate, so this is a hidden pair, not a naked one.
+        candidates[0][0] = HashSet::from([5, 6, 9]);
+        candidates[1][0] = HashSet::from([5, 6, 9]);
+        for row in 2..side {
+            candidates[row][0] = HashSet::from([1, 2, 3]);
+        }
+
+        assert!(eliminate_hidden_pairs(&mut candidates, &filled, side, box_dims));
+        assert_eq!(candidates[0][0], HashSet::from([5, 6]));
+        assert_eq!(candidates[1][0], HashSet::from([5, 6]));
+    }
+
+    #[test]
+    #[allow(clippy::needless_range_loop)]
+    fn eliminate_x_wing_removes_the_digit_from_the_rest_of_the_two_confined_columns() {
+        let side = 9;
+        let filled = vec![vec![false; side]; side];
+        let mut candidates = vec![vec![HashSet::from([1, 9]); side]; side];
+        // Rows 0 and 4 confine 9 to columns 2 and 6, so it can be
+        // eliminated from those columns everywhere else.
+        for &row in &[0, 4] {
+            for col in 0..side {
+                candidates[row][col] = if col == 2 || col == 6 { HashSet::from([9]) } else { HashSet::from([1]) };
+            }
+        }
+
+        assert!(eliminate_x_wing(&mut candidates, &filled, side));
+        for row in 0..side {
+            if row == 0 || row == 4 {
+                continue;
+            }
+            assert!(!candidates[row][2].contains(&9), "at row {row}, col 2");
+            assert!(!candidates[row][6].contains(&9), "at row {row}, col 6");
+        }
+    }
+
+    #[test]
+    fn eliminate_x_wing_makes_no_progress_without_a_confined_rectangle() {
+        let side = 9;
+        let filled = vec![vec![false; side]; side];
+        let candidates = vec![vec![HashSet::from([1, 2, 3, 4, 5, 6, 7, 8, 9]); side]; side];
+
+        let mut working = candidates.clone();
+        assert!(!eliminate_x_wing(&mut working, &filled, side));
+        assert_eq!(working, candidates);
+    }
+
+    #[test]
+    fn eliminate_hidden_pairs_makes_no_progress_without_a_confined_pair() {
+        let side = 9;
+        let box_dims = (3, 3);
+        let filled = vec![vec![false; side]; side];
+        let candidates = vec![vec![HashSet::from([1, 2, 3, 4, 5, 6, 7, 8, 9]); side]; side];
+
+        let mut working = candidates.clone();
+        assert!(!eliminate_hidden_pairs(&mut working, &filled, side, box_dims));
+        assert_eq!(working, candidates);
+    }
+}
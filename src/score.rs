@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use crate::generator::Difficulty;
+
+/// The score a flawless, instant solve would start from, before the time
+/// penalty and any hint or mistake penalties are subtracted.
+const BASE_SCORE: u32 = 10_000;
+
+/// Points lost per second spent solving.
+const SECOND_PENALTY: u32 = 5;
+
+/// Points lost per hint used.
+const HINT_PENALTY: u32 = 300;
+
+/// Points lost per wrong entry made.
+const MISTAKE_PENALTY: u32 = 150;
+
+/// Points lost per solution peek, on par with a hint since it hands the
+/// player at least as much information.
+const PEEK_PENALTY: u32 = 300;
+
+/// Computes a completed game's score from how long it took, how many hints
+/// and solution peeks were used, and how many mistakes were made, then
+/// scales the result by `difficulty`'s multiplier so a harder puzzle is
+/// worth more. Never goes below zero: a slow, hint-heavy, mistake-riddled
+/// game just scores 0.
+pub fn compute(elapsed: Duration, hints_used: u32, peeks_used: u32, mistakes: u32, difficulty: Difficulty) -> u32 {
+    let time_penalty = elapsed.as_secs().saturating_mul(SECOND_PENALTY as u64).min(u32::MAX as u64) as u32;
+    let raw = BASE_SCORE
+        .saturating_sub(time_penalty)
+        .saturating_sub(hints_used.saturating_mul(HINT_PENALTY))
+        .saturating_sub(peeks_used.saturating_mul(PEEK_PENALTY))
+        .saturating_sub(mistakes.saturating_mul(MISTAKE_PENALTY));
+
+    raw * difficulty.score_multiplier()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_flawless_instant_solve_scores_the_base_times_the_multiplier() {
+        let score = compute(Duration::ZERO, 0, 0, 0, Difficulty::Medium);
+        assert_eq!(score, BASE_SCORE * Difficulty::Medium.score_multiplier());
+    }
+
+    #[test]
+    fn time_hints_and_mistakes_each_subtract_before_the_multiplier_is_applied() {
+        let score = compute(Duration::from_secs(20), 2, 0, 1, Difficulty::Easy);
+        let expected = (BASE_SCORE - 20 * SECOND_PENALTY - 2 * HINT_PENALTY - MISTAKE_PENALTY)
+            * Difficulty::Easy.score_multiplier();
+        assert_eq!(score, expected);
+    }
+
+    #[test]
+    fn a_peek_subtracts_like_a_hint_before_the_multiplier_is_applied() {
+        let score = compute(Duration::ZERO, 0, 1, 0, Difficulty::Medium);
+        let expected = (BASE_SCORE - PEEK_PENALTY) * Difficulty::Medium.score_multiplier();
+        assert_eq!(score, expected);
+    }
+
+    #[test]
+    fn the_score_never_goes_below_zero() {
+        let score = compute(Duration::from_secs(100_000), 50, 50, 50, Difficulty::Hard);
+        assert_eq!(score, 0);
+    }
+
+    #[test]
+    fn a_harder_difficulty_scores_higher_for_the_same_performance() {
+        let easy = compute(Duration::from_secs(60), 0, 0, 0, Difficulty::Easy);
+        let hard = compute(Duration::from_secs(60), 0, 0, 0, Difficulty::Hard);
+        assert!(hard > easy);
+    }
+}
@@ -0,0 +1,382 @@
+use crate::board::{Board, Cell};
+
+/// Solves `board` via recursive backtracking with most-constrained-cell
+/// selection. Returns the completed board, or `None` if unsolvable. The
+/// input board is never mutated.
+pub fn solve(board: &Board) -> Option<Board> {
+    if !is_consistent(board) {
+        return None;
+    }
+
+    let mut working = board.clone();
+    if backtrack(&mut working) {
+        Some(working)
+    } else {
+        None
+    }
+}
+
+/// Whether the already-placed digits obey the row/column/box uniqueness
+/// rules. A board that fails this can never be completed, so `solve` checks
+/// it upfront rather than discovering it after an exhaustive search.
+fn is_consistent(board: &Board) -> bool {
+    let side = board.size().side();
+    let (box_rows, box_cols) = board.size().box_dims();
+
+    for row in 0..side {
+        for col in 0..side {
+            let Some(digit) = board.get(row, col).digit() else {
+                continue;
+            };
+            for i in 0..side {
+                if i != col && board.get(row, i).digit() == Some(digit) {
+                    return false;
+                }
+                if i != row && board.get(i, col).digit() == Some(digit) {
+                    return false;
+                }
+            }
+
+            let box_row = (row / box_rows) * box_rows;
+            let box_col = (col / box_cols) * box_cols;
+            for r in box_row..box_row + box_rows {
+                for c in box_col..box_col + box_cols {
+                    if (r, c) != (row, col) && board.get(r, c).digit() == Some(digit) {
+                        return false;
+                    }
+                }
+            }
+
+            if board.is_diagonal() {
+                for (r, c) in diagonal_peers(side, row, col) {
+                    if board.get(r, c).digit() == Some(digit) {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+
+    true
+}
+
+/// The cells sharing a main diagonal with `(row, col)`, not including
+/// `(row, col)` itself, on a board of the given `side`.
+fn diagonal_peers(side: usize, row: usize, col: usize) -> Vec<(usize, usize)> {
+    let mut peers = Vec::new();
+    if row == col {
+        peers.extend((0..side).map(|i| (i, i)));
+    }
+    if row + col == side - 1 {
+        peers.extend((0..side).map(|i| (i, side - 1 - i)));
+    }
+    peers.retain(|&cell| cell != (row, col));
+    peers
+}
+
+/// Counts how many distinct solutions `board` has, stopping early once
+/// `limit` is reached. Used by the generator to check for uniqueness
+/// without paying for a full solution count.
+pub fn count_solutions(board: &Board, limit: usize) -> usize {
+    if !is_consistent(board) {
+        return 0;
+    }
+
+    let mut working = board.clone();
+    let mut count = 0;
+    count_backtrack(&mut working, limit, &mut count);
+    count
+}
+
+fn count_backtrack(board: &mut Board, limit: usize, count: &mut usize) {
+    if *count >= limit {
+        return;
+    }
+
+    let Some((row, col, candidates)) = most_constrained_empty_cell(board) else {
+        *count += 1;
+        return;
+    };
+
+    for digit in candidates {
+        if *count >= limit {
+            return;
+        }
+        board.set(row, col, Cell::Filled(digit));
+        count_backtrack(board, limit, count);
+        board.set(row, col, Cell::Empty);
+    }
+}
+
+fn backtrack(board: &mut Board) -> bool {
+    let Some((row, col, candidates)) = most_constrained_empty_cell(board) else {
+        return true;
+    };
+
+    if candidates.is_empty() {
+        return false;
+    }
+
+    for digit in candidates {
+        board.set(row, col, Cell::Filled(digit));
+        if backtrack(board) {
+            return true;
+        }
+        board.set(row, col, Cell::Empty);
+    }
+
+    false
+}
+
+/// Finds the empty cell with the fewest legal candidates, along with those
+/// candidates. Returns `None` once the board has no empty cells left.
+pub(crate) fn most_constrained_empty_cell(board: &Board) -> Option<(usize, usize, Vec<u8>)> {
+    let side = board.size().side();
+    let mut best: Option<(usize, usize, Vec<u8>)> = None;
+
+    for row in 0..side {
+        for col in 0..side {
+            if board.get(row, col) != Cell::Empty {
+                continue;
+            }
+
+            let candidates = legal_digits(board, row, col);
+            let is_better = match &best {
+                None => true,
+                Some((_, _, best_candidates)) => candidates.len() < best_candidates.len(),
+            };
+            if is_better {
+                let exhausted = candidates.is_empty();
+                best = Some((row, col, candidates));
+                if exhausted {
+                    return best;
+                }
+            }
+        }
+    }
+
+    best
+}
+
+/// The digits `1..=side` that don't already appear in `row`, `col`, or the
+/// containing box.
+fn legal_digits(board: &Board, row: usize, col: usize) -> Vec<u8> {
+    let side = board.size().side() as u8;
+    (1..=side)
+        .filter(|&digit| !conflicts_with_peers(board, row, col, digit))
+        .collect()
+}
+
+fn conflicts_with_peers(board: &Board, row: usize, col: usize, digit: u8) -> bool {
+    let side = board.size().side();
+    let (box_rows, box_cols) = board.size().box_dims();
+
+    for i in 0..side {
+        if board.get(row, i).digit() == Some(digit) {
+            return true;
+        }
+        if board.get(i, col).digit() == Some(digit) {
+            return true;
+        }
+    }
+
+    let box_row = (row / box_rows) * box_rows;
+    let box_col = (col / box_cols) * box_cols;
+    for r in box_row..box_row + box_rows {
+        for c in box_col..box_col + box_cols {
+            if board.get(r, c).digit() == Some(digit) {
+                return true;
+            }
+        }
+    }
+
+    if board.is_diagonal() {
+        for (r, c) in diagonal_peers(side, row, col) {
+            if board.get(r, c).digit() == Some(digit) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::BoardSize;
+
+    fn board_from_grid(grid: [[u8; 9]; 9]) -> Board {
+        let mut board = Board::default();
+        for (row, values) in grid.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                if value != 0 {
+                    board.set(row, col, Cell::Given(value));
+                }
+            }
+        }
+        board
+    }
+
+    #[test]
+    fn solves_a_known_puzzle() {
+        let board = board_from_grid([
+            [5, 3, 0, 0, 7, 0, 0, 0, 0],
+            [6, 0, 0, 1, 9, 5, 0, 0, 0],
+            [0, 9, 8, 0, 0, 0, 0, 6, 0],
+            [8, 0, 0, 0, 6, 0, 0, 0, 3],
+            [4, 0, 0, 8, 0, 3, 0, 0, 1],
+            [7, 0, 0, 0, 2, 0, 0, 0, 6],
+            [0, 6, 0, 0, 0, 0, 2, 8, 0],
+            [0, 0, 0, 4, 1, 9, 0, 0, 5],
+            [0, 0, 0, 0, 8, 0, 0, 7, 9],
+        ]);
+
+        let solution = solve(&board).expect("puzzle should be solvable");
+        for row in 0..9 {
+            for col in 0..9 {
+                assert_ne!(solution.get(row, col), Cell::Empty);
+            }
+            for digit in 1..=9u8 {
+                assert!((0..9).any(|col| solution.get(row, col).digit() == Some(digit)));
+            }
+        }
+    }
+
+    #[test]
+    fn already_solved_board_is_returned_unchanged() {
+        let board = board_from_grid([
+            [5, 3, 4, 6, 7, 8, 9, 1, 2],
+            [6, 7, 2, 1, 9, 5, 3, 4, 8],
+            [1, 9, 8, 3, 4, 2, 5, 6, 7],
+            [8, 5, 9, 7, 6, 1, 4, 2, 3],
+            [4, 2, 6, 8, 5, 3, 7, 9, 1],
+            [7, 1, 3, 9, 2, 4, 8, 5, 6],
+            [9, 6, 1, 5, 3, 7, 2, 8, 4],
+            [2, 8, 7, 4, 1, 9, 6, 3, 5],
+            [3, 4, 5, 2, 8, 6, 1, 7, 9],
+        ]);
+
+        let solution = solve(&board).expect("already-solved board should solve");
+        for row in 0..9 {
+            for col in 0..9 {
+                assert_eq!(solution.get(row, col), board.get(row, col));
+            }
+        }
+    }
+
+    #[test]
+    fn contradictory_board_is_unsolvable() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(0, 1, Cell::Given(5));
+
+        assert_eq!(solve(&board), None);
+    }
+
+    #[test]
+    fn solves_a_4x4_puzzle() {
+        let mut board = Board::new(BoardSize::Mini4);
+        board.set(0, 0, Cell::Given(1));
+        board.set(0, 1, Cell::Given(2));
+        board.set(1, 2, Cell::Given(1));
+
+        let solution = solve(&board).expect("4x4 puzzle should be solvable");
+        for row in 0..4 {
+            for digit in 1..=4u8 {
+                assert!((0..4).any(|col| solution.get(row, col).digit() == Some(digit)));
+            }
+        }
+    }
+
+    #[test]
+    fn solves_a_6x6_puzzle() {
+        let mut board = Board::new(BoardSize::Mini6);
+        board.set(0, 0, Cell::Given(1));
+        board.set(0, 1, Cell::Given(2));
+        board.set(0, 2, Cell::Given(3));
+
+        let solution = solve(&board).expect("6x6 puzzle should be solvable");
+        for row in 0..6 {
+            for digit in 1..=6u8 {
+                assert!((0..6).any(|col| solution.get(row, col).digit() == Some(digit)));
+            }
+        }
+    }
+
+    #[test]
+    fn solves_a_16x16_puzzle() {
+        let mut board = Board::new(BoardSize::Classic16);
+        board.set(0, 0, Cell::Given(1));
+        board.set(0, 1, Cell::Given(2));
+        board.set(4, 4, Cell::Given(1));
+
+        let solution = solve(&board).expect("16x16 puzzle should be solvable");
+        for row in 0..16 {
+            for digit in 1..=16u8 {
+                assert!((0..16).any(|col| solution.get(row, col).digit() == Some(digit)));
+            }
+        }
+    }
+
+    #[test]
+    fn diagonal_solutions_have_no_repeated_digit_on_either_main_diagonal() {
+        let mut board = Board::new(BoardSize::Mini4);
+        board.set_diagonal(true);
+        board.set(0, 0, Cell::Given(1));
+        board.set(0, 1, Cell::Given(2));
+        board.set(1, 2, Cell::Given(1));
+
+        let solution = solve(&board).expect("diagonal puzzle should still be solvable");
+        let side = 4;
+        let main_diagonal: Vec<u8> = (0..side).filter_map(|i| solution.get(i, i).digit()).collect();
+        let anti_diagonal: Vec<u8> =
+            (0..side).filter_map(|i| solution.get(i, side - 1 - i).digit()).collect();
+        let unique = |digits: &[u8]| digits.iter().collect::<std::collections::HashSet<_>>().len();
+        assert_eq!(unique(&main_diagonal), main_diagonal.len());
+        assert_eq!(unique(&anti_diagonal), anti_diagonal.len());
+    }
+
+    #[test]
+    fn count_solutions_reports_one_for_a_uniquely_solvable_puzzle() {
+        let board = board_from_grid([
+            [5, 3, 0, 0, 7, 0, 0, 0, 0],
+            [6, 0, 0, 1, 9, 5, 0, 0, 0],
+            [0, 9, 8, 0, 0, 0, 0, 6, 0],
+            [8, 0, 0, 0, 6, 0, 0, 0, 3],
+            [4, 0, 0, 8, 0, 3, 0, 0, 1],
+            [7, 0, 0, 0, 2, 0, 0, 0, 6],
+            [0, 6, 0, 0, 0, 0, 2, 8, 0],
+            [0, 0, 0, 4, 1, 9, 0, 0, 5],
+            [0, 0, 0, 0, 8, 0, 0, 7, 9],
+        ]);
+
+        assert_eq!(count_solutions(&board, 2), 1);
+    }
+
+    #[test]
+    fn count_solutions_caps_at_the_limit_for_an_under_constrained_puzzle() {
+        let board = Board::default();
+
+        assert_eq!(count_solutions(&board, 2), 2);
+    }
+
+    #[test]
+    fn count_solutions_is_zero_for_a_contradictory_puzzle() {
+        let mut board = Board::default();
+        board.set(0, 0, Cell::Given(5));
+        board.set(0, 1, Cell::Given(5));
+
+        assert_eq!(count_solutions(&board, 2), 0);
+    }
+
+    #[test]
+    fn a_diagonal_conflict_makes_an_otherwise_solvable_board_unsolvable() {
+        let mut board = Board::new(BoardSize::Mini4);
+        board.set_diagonal(true);
+        board.set(0, 0, Cell::Given(1));
+        board.set(1, 1, Cell::Given(1));
+
+        assert_eq!(solve(&board), None);
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::generator::Difficulty;
+
+/// The fastest completion time recorded for each difficulty, in seconds.
+pub type BestTimes = HashMap<Difficulty, u64>;
+
+/// How many games have been completed at a difficulty and how long they
+/// took in total, from which the average completion time is derived.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameStats {
+    pub games_completed: u64,
+    pub total_time_secs: u64,
+}
+
+impl GameStats {
+    /// The average completion time in seconds, or `None` if no games have
+    /// been completed yet.
+    pub fn average_secs(&self) -> Option<u64> {
+        (self.games_completed > 0).then(|| self.total_time_secs / self.games_completed)
+    }
+}
+
+/// Completion stats for each difficulty.
+pub type Stats = HashMap<Difficulty, GameStats>;
+
+/// Records a completed game against `stats`, adding `elapsed` to that
+/// difficulty's total time and incrementing its completed-games count.
+pub fn record_completion(stats: &mut Stats, difficulty: Difficulty, elapsed: Duration) {
+    let entry = stats.entry(difficulty).or_default();
+    entry.games_completed += 1;
+    entry.total_time_secs += elapsed.as_secs();
+}
+
+/// Loads the completion-stats table from `path`, or an empty table if it
+/// doesn't exist yet or can't be parsed.
+pub fn load_stats(path: &Path) -> Stats {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Writes the completion-stats table to `path`, creating parent
+/// directories as needed.
+pub fn save_stats(stats: &Stats, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(stats).map_err(io::Error::other)?;
+    fs::write(path, json)
+}
+
+/// The default location of the completion-stats file, under the OS config
+/// dir.
+pub fn default_stats_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("stats.json")
+}
+
+/// Loads the best-times table from `path`, or an empty table if it doesn't
+/// exist yet or can't be parsed.
+pub fn load(path: &Path) -> BestTimes {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Writes the best-times table to `path`, creating parent directories as
+/// needed.
+pub fn save(times: &BestTimes, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(times).map_err(io::Error::other)?;
+    fs::write(path, json)
+}
+
+/// Records `elapsed` as the best time for `difficulty` if it's faster than
+/// any existing record. Returns whether it set a new record.
+pub fn record_if_better(times: &mut BestTimes, difficulty: Difficulty, elapsed: Duration) -> bool {
+    let secs = elapsed.as_secs();
+    let is_new_best = times.get(&difficulty).is_none_or(|&best| secs < best);
+    if is_new_best {
+        times.insert(difficulty, secs);
+    }
+    is_new_best
+}
+
+/// The default location of the best-times file, under the OS config dir.
+pub fn default_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("best_times.json")
+}
+
+/// The highest score recorded for each difficulty.
+pub type HighScores = HashMap<Difficulty, u32>;
+
+/// Records `score` as the high score for `difficulty` if it beats any
+/// existing one. Returns whether it set a new high score.
+pub fn record_high_score(scores: &mut HighScores, difficulty: Difficulty, score: u32) -> bool {
+    let is_new_high = scores.get(&difficulty).is_none_or(|&best| score > best);
+    if is_new_high {
+        scores.insert(difficulty, score);
+    }
+    is_new_high
+}
+
+/// Loads the high-scores table from `path`, or an empty table if it
+/// doesn't exist yet or can't be parsed.
+pub fn load_high_scores(path: &Path) -> HighScores {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Writes the high-scores table to `path`, creating parent directories as
+/// needed.
+pub fn save_high_scores(scores: &HighScores, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(scores).map_err(io::Error::other)?;
+    fs::write(path, json)
+}
+
+/// The default location of the high-scores file, under the OS config dir.
+pub fn default_high_scores_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("high_scores.json")
+}
+
+/// Appends one row to the play-log CSV at `path` for a completed game:
+/// difficulty, time in seconds, hints used, mistakes, and `date` (left to
+/// the caller so this stays testable without reading the system clock).
+/// Writes the header first if the file doesn't exist yet or is empty, so a
+/// long-running install builds up a single append-only log.
+pub fn export_csv(
+    path: &Path,
+    difficulty: Difficulty,
+    elapsed: Duration,
+    hints_used: u32,
+    mistakes: u32,
+    date: &str,
+) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let needs_header = fs::metadata(path).map(|meta| meta.len() == 0).unwrap_or(true);
+
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+    if needs_header {
+        writeln!(file, "difficulty,time_secs,hints,mistakes,date")?;
+    }
+    writeln!(file, "{:?},{},{},{},{date}", difficulty, elapsed.as_secs(), hints_used, mistakes)?;
+    Ok(())
+}
+
+/// The default location of the play-log CSV, under the OS config dir.
+pub fn default_csv_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("history.csv")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_if_better_only_updates_on_improvement() {
+        let mut times = BestTimes::new();
+
+        assert!(record_if_better(
+            &mut times,
+            Difficulty::Medium,
+            Duration::from_secs(120)
+        ));
+        assert_eq!(times[&Difficulty::Medium], 120);
+
+        assert!(!record_if_better(
+            &mut times,
+            Difficulty::Medium,
+            Duration::from_secs(150)
+        ));
+        assert_eq!(times[&Difficulty::Medium], 120);
+
+        assert!(record_if_better(
+            &mut times,
+            Difficulty::Medium,
+            Duration::from_secs(90)
+        ));
+        assert_eq!(times[&Difficulty::Medium], 90);
+    }
+
+    #[test]
+    fn save_and_load_round_trips() {
+        let mut times = BestTimes::new();
+        times.insert(Difficulty::Easy, 200);
+        times.insert(Difficulty::Hard, 400);
+
+        let dir = std::env::temp_dir().join(format!("rusuku-stats-test-{}", std::process::id()));
+        let path = dir.join("best_times.json");
+
+        save(&times, &path).unwrap();
+        let loaded = load(&path);
+
+        assert_eq!(loaded, times);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn record_completion_accumulates_count_and_average() {
+        let mut stats = Stats::new();
+
+        record_completion(&mut stats, Difficulty::Medium, Duration::from_secs(100));
+        record_completion(&mut stats, Difficulty::Medium, Duration::from_secs(200));
+
+        let medium = stats[&Difficulty::Medium];
+        assert_eq!(medium.games_completed, 2);
+        assert_eq!(medium.total_time_secs, 300);
+        assert_eq!(medium.average_secs(), Some(150));
+    }
+
+    #[test]
+    fn average_secs_is_none_before_any_completion() {
+        assert_eq!(GameStats::default().average_secs(), None);
+    }
+
+    #[test]
+    fn record_high_score_only_updates_on_improvement() {
+        let mut scores = HighScores::new();
+
+        assert!(record_high_score(&mut scores, Difficulty::Medium, 500));
+        assert_eq!(scores[&Difficulty::Medium], 500);
+
+        assert!(!record_high_score(&mut scores, Difficulty::Medium, 300));
+        assert_eq!(scores[&Difficulty::Medium], 500);
+
+        assert!(record_high_score(&mut scores, Difficulty::Medium, 900));
+        assert_eq!(scores[&Difficulty::Medium], 900);
+    }
+
+    #[test]
+    fn save_and_load_high_scores_round_trips() {
+        let mut scores = HighScores::new();
+        scores.insert(Difficulty::Easy, 1200);
+        scores.insert(Difficulty::Hard, 3400);
+
+        let dir = std::env::temp_dir().join(format!("rusuku-high-scores-test-{}", std::process::id()));
+        let path = dir.join("high_scores.json");
+
+        save_high_scores(&scores, &path).unwrap();
+        let loaded = load_high_scores(&path);
+
+        assert_eq!(loaded, scores);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn save_stats_and_load_stats_round_trips() {
+        let mut stats = Stats::new();
+        record_completion(&mut stats, Difficulty::Easy, Duration::from_secs(50));
+
+        let dir = std::env::temp_dir().join(format!("rusuku-stats-completion-test-{}", std::process::id()));
+        let path = dir.join("stats.json");
+
+        save_stats(&stats, &path).unwrap();
+        let loaded = load_stats(&path);
+
+        assert_eq!(loaded, stats);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn export_csv_writes_the_header_once_then_appends_a_row_per_game() {
+        let dir = std::env::temp_dir().join(format!("rusuku-csv-export-test-{}", std::process::id()));
+        let path = dir.join("history.csv");
+
+        export_csv(&path, Difficulty::Easy, Duration::from_secs(120), 1, 0, "2026-08-08").unwrap();
+        export_csv(&path, Difficulty::Hard, Duration::from_secs(300), 3, 2, "2026-08-09").unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "difficulty,time_secs,hints,mistakes,date");
+        assert_eq!(lines[1], "Easy,120,1,0,2026-08-08");
+        assert_eq!(lines[2], "Hard,300,3,2,2026-08-09");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,230 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+
+/// The built-in color themes a player can select in config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThemeName {
+    #[default]
+    Default,
+    HighContrast,
+    Solarized,
+}
+
+impl ThemeName {
+    /// The next theme in the cycle, wrapping back to `Default` after the
+    /// last one, for the settings screen to step through with Enter/Space.
+    pub fn next(self) -> Self {
+        match self {
+            ThemeName::Default => ThemeName::HighContrast,
+            ThemeName::HighContrast => ThemeName::Solarized,
+            ThemeName::Solarized => ThemeName::Default,
+        }
+    }
+}
+
+impl std::fmt::Display for ThemeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeName::Default => write!(f, "Default"),
+            ThemeName::HighContrast => write!(f, "High contrast"),
+            ThemeName::Solarized => write!(f, "Solarized"),
+        }
+    }
+}
+
+/// The styles used to render the board and header, grouped so a whole look
+/// can be swapped at once instead of threading individual colors through
+/// the rendering code.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub given: Style,
+    pub filled: Style,
+    pub cursor: Style,
+    pub conflict: Style,
+    pub wrong: Style,
+    pub peer_highlight: Style,
+    pub selected_digit_highlight: Style,
+    pub timer: Style,
+    pub border: Style,
+    /// A subtle tint marking the two main diagonals in the X-Sudoku variant.
+    pub diagonal: Style,
+    /// Marks an empty cell with exactly one legal candidate, for the
+    /// coaching overlay.
+    pub coaching_highlight: Style,
+    /// Marks the cell(s) a pending teaching hint refers to, until the
+    /// player applies or drops it.
+    pub hint_highlight: Style,
+    /// Fades out cells that don't hold and can't legally hold the focused
+    /// digit, for the digit focus scanning mode.
+    pub dimmed: Style,
+    /// A subtle background tint applied to alternating boxes when
+    /// `Settings::box_shading` is on, so box boundaries read clearly even
+    /// on terminals whose border rendering is weak.
+    pub box_shade: Style,
+    /// Briefly flashed over a row, column, or box the player just
+    /// completed correctly.
+    pub unit_complete: Style,
+}
+
+impl Theme {
+    pub fn named(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Default => Self::default_theme(),
+            ThemeName::HighContrast => Self::high_contrast(),
+            ThemeName::Solarized => Self::solarized(),
+        }
+    }
+
+    /// The look the game shipped with, matching the previously hardcoded
+    /// colors so switching themes back to this one is a no-op visually.
+    fn default_theme() -> Self {
+        Self {
+            given: Style::new().fg(Color::White).add_modifier(Modifier::BOLD),
+            filled: Style::new().fg(Color::Cyan),
+            cursor: Style::new().add_modifier(Modifier::REVERSED),
+            conflict: Style::new().bg(Color::Red),
+            wrong: Style::new().fg(Color::Red),
+            peer_highlight: Style::new().bg(Color::DarkGray),
+            selected_digit_highlight: Style::new().bg(Color::Blue),
+            timer: Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            border: Style::new(),
+            diagonal: Style::new().add_modifier(Modifier::DIM),
+            coaching_highlight: Style::new().fg(Color::Green),
+            hint_highlight: Style::new().bg(Color::Magenta),
+            dimmed: Style::new().fg(Color::DarkGray).add_modifier(Modifier::DIM),
+            box_shade: Style::new().bg(Color::Rgb(0x20, 0x20, 0x20)),
+            unit_complete: Style::new().bg(Color::Green),
+        }
+    }
+
+    /// Wide color separation for players in low-fidelity or unusual
+    /// terminal color setups.
+    fn high_contrast() -> Self {
+        Self {
+            given: Style::new().fg(Color::White).add_modifier(Modifier::BOLD),
+            filled: Style::new().fg(Color::Black).bg(Color::White),
+            cursor: Style::new().fg(Color::Black).bg(Color::Yellow),
+            conflict: Style::new().fg(Color::White).bg(Color::Red),
+            wrong: Style::new().fg(Color::White).bg(Color::Red),
+            peer_highlight: Style::new().bg(Color::Gray),
+            selected_digit_highlight: Style::new().fg(Color::Black).bg(Color::Cyan),
+            timer: Style::new().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD),
+            border: Style::new().fg(Color::White),
+            diagonal: Style::new().add_modifier(Modifier::DIM),
+            coaching_highlight: Style::new().fg(Color::Black).bg(Color::Green),
+            hint_highlight: Style::new().fg(Color::Black).bg(Color::Magenta),
+            dimmed: Style::new().fg(Color::Gray).add_modifier(Modifier::DIM),
+            box_shade: Style::new().bg(Color::DarkGray),
+            unit_complete: Style::new().fg(Color::Black).bg(Color::Green),
+        }
+    }
+
+    /// The Solarized dark palette (<https://ethanschoonover.com/solarized/>).
+    fn solarized() -> Self {
+        let base03 = Color::Rgb(0x00, 0x2b, 0x36);
+        let base0 = Color::Rgb(0x83, 0x94, 0x96);
+        let base2 = Color::Rgb(0xee, 0xe8, 0xd5);
+        let yellow = Color::Rgb(0xb5, 0x89, 0x00);
+        let orange = Color::Rgb(0xcb, 0x4b, 0x16);
+        let red = Color::Rgb(0xdc, 0x32, 0x2f);
+        let blue = Color::Rgb(0x26, 0x8b, 0xd2);
+        let cyan = Color::Rgb(0x2a, 0xa1, 0x98);
+        let magenta = Color::Rgb(0xd3, 0x36, 0x82);
+
+        Self {
+            given: Style::new().fg(base2).add_modifier(Modifier::BOLD),
+            filled: Style::new().fg(cyan),
+            cursor: Style::new().fg(base03).bg(yellow),
+            conflict: Style::new().bg(red),
+            wrong: Style::new().fg(red),
+            peer_highlight: Style::new().bg(base03),
+            selected_digit_highlight: Style::new().bg(blue),
+            timer: Style::new().fg(orange).add_modifier(Modifier::BOLD),
+            border: Style::new().fg(base0),
+            diagonal: Style::new().add_modifier(Modifier::DIM),
+            coaching_highlight: Style::new().fg(cyan).add_modifier(Modifier::BOLD),
+            hint_highlight: Style::new().fg(base03).bg(magenta),
+            dimmed: Style::new().fg(base0).add_modifier(Modifier::DIM),
+            box_shade: Style::new().bg(base03),
+            unit_complete: Style::new().fg(base03).bg(Color::Rgb(0x85, 0x99, 0x00)),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::named(ThemeName::default())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ThemeConfig {
+    name: ThemeName,
+}
+
+/// Loads the active theme's name from `path`, a small TOML file with a
+/// `name` field, falling back to `Default` if the file is absent or can't
+/// be parsed.
+pub fn load_name(path: &Path) -> ThemeName {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|toml| toml::from_str::<ThemeConfig>(&toml).ok())
+        .map(|config| config.name)
+        .unwrap_or_default()
+}
+
+/// Loads the active theme from `path`, resolving `load_name`'s result into
+/// its styles.
+pub fn load(path: &Path) -> Theme {
+    Theme::named(load_name(path))
+}
+
+/// Writes `name` to `path` as TOML, creating parent directories as needed,
+/// so a theme picked in the settings screen survives a restart.
+pub fn save(name: ThemeName, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let toml = toml::to_string_pretty(&ThemeConfig { name }).map_err(io::Error::other)?;
+    fs::write(path, toml)
+}
+
+/// The default location of the theme config file, under the OS config dir.
+pub fn default_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("rusuku")
+        .join("theme.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_named_theme_overrides_the_default() {
+        let dir = std::env::temp_dir().join(format!("rusuku-theme-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("theme.toml");
+        fs::write(&path, "name = \"HighContrast\"\n").unwrap();
+
+        let theme = load(&path);
+
+        assert_eq!(theme.cursor, Theme::high_contrast().cursor);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn a_missing_file_falls_back_to_the_default_theme() {
+        let path = std::env::temp_dir().join("rusuku-theme-test-missing-file.toml");
+
+        let theme = load(&path);
+
+        assert_eq!(theme.given, Theme::default_theme().given);
+    }
+}
@@ -3,6 +3,7 @@ use std::io::{self, stdout, Stdout};
 use ratatui::{
     backend::CrosstermBackend,
     crossterm::{
+        event::{DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture},
         execute,
         terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     },
@@ -14,14 +15,53 @@ pub type Tui = Terminal<CrosstermBackend<Stdout>>;
 
 /// Initialize the terminal
 pub fn init() -> io::Result<Tui> {
-    execute!(stdout(), EnterAlternateScreen)?;
+    execute!(
+        stdout(),
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableFocusChange
+    )?;
     enable_raw_mode()?;
     Terminal::new(CrosstermBackend::new(stdout()))
 }
 
 /// Restore the terminal to its original state
 pub fn restore() -> io::Result<()> {
-    execute!(stdout(), LeaveAlternateScreen)?;
+    execute!(stdout(), DisableFocusChange, DisableMouseCapture, LeaveAlternateScreen)?;
     disable_raw_mode()?;
     Ok(())
 }
+
+/// Restores the terminal to its normal state. Abstracted away from `App`'s
+/// draw-error recovery so a test can substitute a mock instead of a real
+/// implementation that issues actual terminal escape codes.
+pub trait Restore: std::fmt::Debug {
+    fn restore(&self) -> io::Result<()>;
+}
+
+/// The real restore, backed by `tui::restore`.
+#[derive(Debug, Default)]
+pub struct SystemRestore;
+
+impl Restore for SystemRestore {
+    fn restore(&self) -> io::Result<()> {
+        restore()
+    }
+}
+
+impl Default for Box<dyn Restore> {
+    fn default() -> Self {
+        Box::new(SystemRestore)
+    }
+}
+
+/// Wraps the default panic hook so a panic restores the terminal first,
+/// instead of leaving it in raw/alternate-screen mode with the panic message
+/// swallowed or mangled underneath it.
+pub fn install_panic_hook() {
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = restore();
+        original_hook(panic_info);
+    }));
+}